@@ -16,8 +16,11 @@
 
 use crate::error::Error;
 use fish_shoal_gui::FishShoalGui;
-use fish_shoal_simulator::{Config, FishShoalSimulator, SimulatorOutput};
+use fish_shoal_simulator::{
+    Config, ConfigError, Error as SimulatorError, FishShoalSimulator, SimulatorOutput,
+};
 use std::{
+    mem,
     sync::mpsc::{self, Receiver, Sender},
     thread::{self, JoinHandle},
 };
@@ -34,32 +37,48 @@ impl FishShoalApp {
         let (data_sender, data_receiver): (Sender<SimulatorOutput>, Receiver<SimulatorOutput>) =
             mpsc::channel::<SimulatorOutput>();
 
-        let gui: FishShoalGui = FishShoalGui::new(data_receiver, cfg_sender);
+        let (buffer_sender, buffer_receiver): (Sender<SimulatorOutput>, Receiver<SimulatorOutput>) =
+            mpsc::channel::<SimulatorOutput>();
+
+        let gui: FishShoalGui = FishShoalGui::new(data_receiver, buffer_sender, cfg_sender);
 
-        let sim_thread: JoinHandle<Result<(), Error>> = thread::spawn(move || {
-            loop {
-                let cfg: Config = match cfg_receiver.recv() {
-                    Ok(cfg) => cfg,
-                    Err(_) => break,
-                };
+        let sim_thread: JoinHandle<Result<(), SimulatorError>> = thread::spawn(move || {
+            let mut rejected: Option<ConfigError> = None;
 
-                let data_sender: Sender<SimulatorOutput> = data_sender.clone();
+            while let Ok(cfg) = cfg_receiver.recv() {
+                // Frames are swapped with the buffers the GUI hands back, so none is allocated
+                // once both sides hold one.
+                let result: Result<(), SimulatorError> =
+                    sim.run_with(|output: &mut SimulatorOutput| {
+                        let spare: SimulatorOutput = buffer_receiver.try_recv().unwrap_or_default();
+                        if data_sender.send(mem::replace(output, spare)).is_err() {
+                            return Config {
+                                running: false,
+                                ..Default::default()
+                            };
+                        }
+                        cfg.clone()
+                    });
 
-                sim.run(move |output: SimulatorOutput| {
-                    if data_sender.send(output).is_err() {
-                        return Config {
-                            running: false,
-                            ..Default::default()
-                        };
+                // A rejected config keeps the previous one running, reported once until it changes.
+                match result {
+                    Ok(()) => rejected = None,
+                    Err(SimulatorError::Config(err)) => {
+                        if rejected.as_ref() != Some(&err) {
+                            eprintln!("Fish Shoal App ignored an invalid config: {err}");
+                        }
+                        rejected = Some(err);
                     }
-                    cfg
-                })
-                .map_err(Error::Simulator)?;
+                    Err(err) => return Err(err),
+                }
             }
             Ok(())
         });
 
         gui.run().map_err(Error::Gui)?;
-        sim_thread.join().map_err(Error::Thread)?
+        sim_thread
+            .join()
+            .map_err(Error::Thread)?
+            .map_err(Error::Simulator)
     }
 }
@@ -33,9 +33,16 @@ impl Display for Error {
             f,
             "Fish Shoal App error caused by {}",
             match self {
-                Self::Gui(source) => format!("GUI: {source}"),
-                Self::Simulator(source) => format!("Simulator: {source}"),
-                Self::Thread(source) => format!("thread: {:?}", source),
+                Self::Gui(_) => "the GUI".to_string(),
+                Self::Simulator(_) => "the simulator".to_string(),
+                Self::Thread(payload) => {
+                    let message: &str = payload
+                        .downcast_ref::<&str>()
+                        .copied()
+                        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                        .unwrap_or("unknown panic");
+                    format!("a panic in the simulator thread: {message}")
+                }
             }
         )
     }
@@ -18,7 +18,19 @@ mod app;
 mod error;
 
 use app::FishShoalApp;
+use std::{error::Error, process::ExitCode};
 
-fn main() -> Result<(), error::Error> {
-    FishShoalApp::run()
+fn main() -> ExitCode {
+    let Err(err) = FishShoalApp::run() else {
+        return ExitCode::SUCCESS;
+    };
+
+    eprintln!("{err}");
+    let mut source: Option<&dyn Error> = err.source();
+    while let Some(cause) = source {
+        eprintln!("  caused by: {cause}");
+        source = cause.source();
+    }
+
+    ExitCode::FAILURE
 }
@@ -25,6 +25,7 @@ use fish_shoal_simulator::SimulatorOutput;
 
 const FISH_LENGTH: f32 = 10.0;
 const FISH_HEAD_RADIUS: f32 = 3.0;
+const PREDATOR_SCALE: f32 = 2.5;
 
 pub struct Entities;
 
@@ -33,12 +34,29 @@ impl Entities {
         app: &mut FishShoalGui,
         primary_pressed: bool,
         painter: Painter,
-        data: SimulatorOutput,
+        data: &SimulatorOutput,
         origin: Pos2,
     ) {
+        app.focused_fish_data = None;
+
         for idx in 0..data.ids.len() {
-            Self::render_entity(idx, app, primary_pressed, &painter, &data, origin);
+            Self::render_entity(idx, app, primary_pressed, &painter, data, origin);
         }
+
+        for idx in 0..data.predator_positions.len() {
+            Self::render_predator(idx, &painter, data, origin);
+        }
+    }
+
+    fn render_predator(idx: usize, painter: &Painter, data: &SimulatorOutput, origin: Pos2) {
+        let position: [f32; 2] = data.predator_positions[idx];
+        let velocity: [f32; 2] = data.predator_velocities[idx];
+
+        let position: Pos2 = origin + Vec2::new(position[0], position[1]);
+        let velocity: Vec2 = Vec2::new(velocity[0], velocity[1]);
+
+        let points: Vec<Pos2> = Self::fish(position, velocity, PREDATOR_SCALE);
+        painter.add(Shape::convex_polygon(points, Color32::RED, Stroke::NONE));
     }
 
     fn render_entity(
@@ -66,12 +84,13 @@ impl Entities {
         if speed > 0.1 {
             let velocity: Vec2 = Vec2::new(velocity[0], velocity[1]);
 
-            let points: Vec<Pos2> = Self::fish(position, velocity);
+            let points: Vec<Pos2> = Self::fish(position, velocity, 1.0);
 
-            if primary_pressed && let Some([mx, my]) = app.config.mouse_pos {
-                if position.distance(Pos2::new(mx, my)) <= FISH_LENGTH {
-                    app.focused_fish_id = Some(id);
-                }
+            if primary_pressed
+                && let Some([mx, my]) = app.config.mouse_pos
+                && position.distance(Pos2::new(mx, my)) <= FISH_LENGTH
+            {
+                app.focused_fish_id = Some(id);
             }
 
             painter.add(Shape::convex_polygon(points, color, Stroke::NONE));
@@ -83,26 +102,29 @@ impl Entities {
         }
     }
 
-    fn fish(position: Pos2, velocity: Vec2) -> Vec<Pos2> {
+    fn fish(position: Pos2, velocity: Vec2, scale: f32) -> Vec<Pos2> {
         let direction: Vec2 = velocity.normalized();
 
         let right: Vec2 = Vec2::new(direction.y, -direction.x);
 
-        let head_center: Pos2 = position + direction * (FISH_LENGTH * 0.2);
-        let tail_tip: Pos2 = head_center - direction * (FISH_LENGTH * 0.8);
+        let length: f32 = FISH_LENGTH * scale;
+        let head_radius: f32 = FISH_HEAD_RADIUS * scale;
+
+        let head_center: Pos2 = position + direction * (length * 0.2);
+        let tail_tip: Pos2 = head_center - direction * (length * 0.8);
 
         let diag_right: Vec2 = (direction + right).normalized();
         let diag_left: Vec2 = (direction - right).normalized();
 
-        let nose_len: f32 = FISH_HEAD_RADIUS * 1.6;
+        let nose_len: f32 = head_radius * 1.6;
 
         vec![
             tail_tip,
-            head_center + right * FISH_HEAD_RADIUS,
-            head_center + diag_right * FISH_HEAD_RADIUS,
+            head_center + right * head_radius,
+            head_center + diag_right * head_radius,
             head_center + direction * nose_len,
-            head_center + diag_left * FISH_HEAD_RADIUS,
-            head_center - right * FISH_HEAD_RADIUS,
+            head_center + diag_left * head_radius,
+            head_center - right * head_radius,
         ]
     }
 
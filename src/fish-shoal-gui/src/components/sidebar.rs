@@ -18,10 +18,11 @@ use super::AlgoRadiusFov;
 use crate::FishShoalGui;
 use eframe::emath::Rect;
 use eframe::{
-    egui::{Align, Context, Layout, RichText, SidePanel, Slider},
-    emath::Vec2,
     Frame,
+    egui::{Align, ComboBox, Context, DragValue, Layout, RichText, SidePanel, Slider},
+    emath::Vec2,
 };
+use fish_shoal_simulator::{Arena, RemovalPolicy, ResizePolicy, SpawnPattern};
 
 pub struct SideBar;
 
@@ -47,10 +48,8 @@ impl SideBar {
                 };
                 ui.horizontal(|ui| {
                     ui.label(format!("• Focused fish: {focused_fish_id}"));
-                    if app.focused_fish_id.is_some() {
-                        if ui.button("Stop").clicked() {
-                            app.focused_fish_id = None;
-                        }
+                    if app.focused_fish_id.is_some() && ui.button("Stop").clicked() {
+                        app.focused_fish_id = None;
                     }
                 });
                 if let Some(fish) = &app.focused_fish_data {
@@ -78,20 +77,165 @@ impl SideBar {
                     if ui.selectable_label(app.config.paused, "Pause").clicked() {
                         app.config.paused = true;
                     }
+                    if ui.button("Step").clicked() {
+                        app.config.paused = true;
+                        app.config.steps += 1;
+                    }
                 });
+                ui.add(
+                    Slider::new(&mut app.config.time_scale, 0.1..=4.0)
+                        .suffix("x")
+                        .text("Time scale"),
+                );
 
                 ui.separator();
                 ui.heading(RichText::new("Entities").size(14.0));
                 ui.add(Slider::new(&mut app.config.entity_count, 0..=10_000).text("Count"));
+                let center: fish_shoal_simulator::Vec2 = [
+                    app.config.width as f32 / 2.0,
+                    app.config.height as f32 / 2.0,
+                ]
+                .into();
+                let radius: f32 = app.config.width.min(app.config.height) as f32 / 6.0;
+                ComboBox::from_label("Spawn")
+                    .selected_text(app.config.spawn_pattern.name())
+                    .show_ui(ui, |ui| {
+                        for pattern in [
+                            SpawnPattern::Uniform,
+                            SpawnPattern::Ball { center, radius },
+                            SpawnPattern::Block {
+                                center,
+                                size: [radius * 2.0, radius].into(),
+                                heading: [1.0, 0.0].into(),
+                            },
+                            SpawnPattern::Mill {
+                                center,
+                                inner_radius: radius / 2.0,
+                                outer_radius: radius,
+                                clockwise: false,
+                            },
+                            SpawnPattern::Ring { center, radius },
+                            SpawnPattern::Grid {
+                                center,
+                                spacing: app.config.avoidance_radius,
+                            },
+                            SpawnPattern::Clusters {
+                                count: 4,
+                                radius: radius / 2.0,
+                            },
+                            SpawnPattern::Point {
+                                position: center,
+                                heading: [1.0, 0.0].into(),
+                                spread: 45.0,
+                            },
+                        ] {
+                            let selected: bool = app.config.spawn_pattern.name() == pattern.name();
+                            if ui.selectable_label(selected, pattern.name()).clicked() {
+                                app.config.spawn_pattern = pattern;
+                            }
+                        }
+                    });
+                let focused_fish_ids: Vec<usize> = app.focused_fish_id.into_iter().collect();
+                let region: RemovalPolicy = RemovalPolicy::Region {
+                    min: [0.0, 0.0].into(),
+                    max: [app.config.width as f32 / 2.0, app.config.height as f32].into(),
+                };
+                ComboBox::from_label("Removal")
+                    .selected_text(app.config.removal_policy.name())
+                    .show_ui(ui, |ui| {
+                        for policy in [
+                            RemovalPolicy::Random,
+                            RemovalPolicy::Oldest,
+                            RemovalPolicy::Newest,
+                            RemovalPolicy::MostIsolated,
+                            region,
+                            RemovalPolicy::Ids(focused_fish_ids),
+                        ] {
+                            let selected: bool = app.config.removal_policy.name() == policy.name();
+                            let text: &str = match policy {
+                                RemovalPolicy::Ids(_) => "Focused fish",
+                                _ => policy.name(),
+                            };
+                            if ui.selectable_label(selected, text).clicked() {
+                                app.config.removal_policy = policy;
+                            }
+                        }
+                    });
+                if let RemovalPolicy::Region { min, max } = &mut app.config.removal_policy {
+                    ui.horizontal(|ui| {
+                        ui.label("From");
+                        ui.add(DragValue::new(&mut min.x).prefix("x: "));
+                        ui.add(DragValue::new(&mut min.y).prefix("y: "));
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("To");
+                        ui.add(DragValue::new(&mut max.x).prefix("x: "));
+                        ui.add(DragValue::new(&mut max.y).prefix("y: "));
+                    });
+                }
+
+                ui.separator();
+                ui.heading(RichText::new("Predators").size(14.0));
+                ui.add(Slider::new(&mut app.config.predator_count, 0..=10).text("Count"));
+                ui.add(Slider::new(&mut app.config.predator_speed, 10.0..=200.0).text("Speed"));
 
                 ui.separator();
                 ui.heading(RichText::new("Area").size(14.0));
+                ui.horizontal(|ui| {
+                    if ui
+                        .selectable_label(app.config.arena == Arena::Rectangle, "Rectangle")
+                        .clicked()
+                    {
+                        app.config.arena = Arena::Rectangle;
+                    }
+                    if ui
+                        .selectable_label(app.config.arena == Arena::Circle, "Circle")
+                        .clicked()
+                    {
+                        app.config.arena = Arena::Circle;
+                    }
+                    if ui
+                        .selectable_label(app.config.arena == Arena::Open, "Open")
+                        .clicked()
+                    {
+                        app.config.arena = Arena::Open;
+                    }
+                    if let Arena::Polygon(_) = app.config.arena {
+                        let _ = ui.selectable_label(true, "Polygon");
+                    }
+                });
                 ui.add(
                     Slider::new(&mut app.config.width, 100..=app.screen.x as usize).text("Width"),
                 );
                 ui.add(
                     Slider::new(&mut app.config.height, 100..=app.screen.y as usize).text("Height"),
                 );
+                ComboBox::from_label("Resize")
+                    .selected_text(app.config.resize_policy.name())
+                    .show_ui(ui, |ui| {
+                        for policy in ResizePolicy::ALL {
+                            let selected: bool = app.config.resize_policy == policy;
+                            if ui.selectable_label(selected, policy.name()).clicked() {
+                                app.config.resize_policy = policy;
+                            }
+                        }
+                    });
+                let mut auto_chunk_size: bool = app.config.chunk_size.is_none();
+                ui.horizontal(|ui| {
+                    if ui.checkbox(&mut auto_chunk_size, "Auto grid").changed() {
+                        app.config.chunk_size = if auto_chunk_size {
+                            None
+                        } else {
+                            Some(app.chunk_size.max(5.0))
+                        };
+                    }
+                    if auto_chunk_size {
+                        ui.label(format!("{:.1}", app.chunk_size));
+                    }
+                });
+                if let Some(chunk_size) = &mut app.config.chunk_size {
+                    ui.add(Slider::new(chunk_size, 5.0..=200.0).text("Grid cell"));
+                }
 
                 ui.separator();
                 ui.heading(RichText::new("Idle behavior change probability").size(14.0));
@@ -141,6 +285,21 @@ impl SideBar {
                     .attraction_radius
                     .clamp(app.config.alignment_radius + 1.0, 100.0);
 
+                ui.separator();
+                ui.heading(RichText::new("Escape behavior").size(14.0));
+                ui.add(
+                    Slider::new(&mut app.config.threat_radius, 10.0..=300.0).text("Threat radius"),
+                );
+                ui.add(
+                    Slider::new(&mut app.config.escape_speed, 50.0..=300.0).text("Escape speed"),
+                );
+                ui.add(Slider::new(&mut app.config.burst_speed, 100.0..=500.0).text("Burst speed"));
+                ui.add(
+                    Slider::new(&mut app.config.refractory_period, 0.0..=10.0)
+                        .suffix(" s")
+                        .text("Refractory"),
+                );
+
                 ui.separator();
                 ui.heading(RichText::new("Shoal behavior fov").size(14.0));
                 ui.add(
@@ -160,7 +319,7 @@ impl SideBar {
                         app,
                         rect.center(),
                         Vec2::new(0.0, -1.0),
-                        &sub_ui.painter(),
+                        sub_ui.painter(),
                         true,
                     );
                 });
@@ -168,13 +327,13 @@ impl SideBar {
                 ui.with_layout(Layout::bottom_up(Align::Center), |ui| {
                     ui.add_space(10.0);
 
-                    if !app.is_latest_version {
-                        if let Some(download_link) = &app.latest_version_download_link {
-                            ui.hyperlink_to(
-                                RichText::new(">> download latest <<").size(10.0),
-                                download_link,
-                            );
-                        }
+                    if !app.is_latest_version
+                        && let Some(download_link) = &app.latest_version_download_link
+                    {
+                        ui.hyperlink_to(
+                            RichText::new(">> download latest <<").size(10.0),
+                            download_link,
+                        );
                     }
 
                     ui.label(RichText::new(&app.version_msg).size(10.0));
@@ -15,11 +15,12 @@
  */
 use crate::{Entities, FishShoalGui};
 use eframe::{
-    egui::{CentralPanel, Context, Painter},
+    Frame,
+    egui::{CentralPanel, Context, Painter, Shape},
     emath::{Pos2, Rect, Vec2},
     epaint::{Color32, Stroke, StrokeKind},
-    Frame,
 };
+use fish_shoal_simulator::Arena;
 #[cfg(debug_assertions)]
 use fish_shoal_simulator::SimulatorOutput;
 
@@ -45,7 +46,12 @@ impl Simulation {
                 {
                     Self::check_simulator_output(&output);
                 }
-                Entities::render(app, primary_pressed, painter, output, area.left_top());
+                app.chunk_size = output.chunk_size;
+                let origin: Pos2 =
+                    area.left_top() - Vec2::new(app.config.origin[0], app.config.origin[1]);
+                Entities::render(app, primary_pressed, painter, &output, origin);
+                // The rendered frame is handed back to the simulator to be refilled.
+                let _ = app.buffer_sender.send(output);
             }
         });
     }
@@ -59,27 +65,51 @@ impl Simulation {
             Vec2::new(app.config.width as f32, app.config.height as f32),
         );
 
-        painter.rect_stroke(
-            area,
-            0.0,
-            Stroke::new(1.0, Color32::WHITE),
-            StrokeKind::Middle,
-        );
+        let stroke: Stroke = Stroke::new(1.0, Color32::WHITE);
+
+        match &app.config.arena {
+            Arena::Rectangle => {
+                painter.rect_stroke(area, 0.0, stroke, StrokeKind::Middle);
+            }
+            Arena::Circle => {
+                let (center, radius): (fish_shoal_simulator::Vec2, f32) =
+                    Arena::circle(area.width(), area.height());
+                painter.circle_stroke(area.min + Vec2::new(center.x, center.y), radius, stroke);
+            }
+            Arena::Polygon(vertices) => {
+                let points: Vec<Pos2> = vertices
+                    .iter()
+                    .map(|vertex| area.min + Vec2::new(vertex.x, vertex.y))
+                    .collect();
+                painter.add(Shape::closed_line(points, stroke));
+            }
+            Arena::Open => (),
+        }
 
         area
     }
 
     fn build_grid(app: &mut FishShoalGui, area: Rect, painter: &Painter) {
-        let cell_size: f32 = app.config.attraction_radius;
+        let cell_size: f32 = app.chunk_size;
+        if cell_size < 1.0 {
+            return;
+        }
+
         let stroke: Stroke = Stroke::new(0.05, Color32::GRAY);
 
-        let mut x: f32 = area.min.x + cell_size;
+        let mut x: f32 = area.min.x + (-app.config.origin[0]).rem_euclid(cell_size);
+        if x <= area.min.x {
+            x += cell_size;
+        }
         while x < area.max.x {
             painter.line_segment([Pos2::new(x, area.min.y), Pos2::new(x, area.max.y)], stroke);
             x += cell_size;
         }
 
-        let mut y: f32 = area.min.y + cell_size;
+        let mut y: f32 = area.min.y + (-app.config.origin[1]).rem_euclid(cell_size);
+        if y <= area.min.y {
+            y += cell_size;
+        }
         while y < area.max.y {
             painter.line_segment([Pos2::new(area.min.x, y), Pos2::new(area.max.x, y)], stroke);
             y += cell_size;
@@ -87,10 +117,10 @@ impl Simulation {
     }
 
     fn get_mouse_position(ctx: &Context, area: Rect) -> Option<[f32; 2]> {
-        if let Some(mouse_pos) = ctx.pointer_hover_pos() {
-            if area.contains(mouse_pos) {
-                return Some([mouse_pos.x, mouse_pos.y]);
-            }
+        if let Some(mouse_pos) = ctx.pointer_hover_pos()
+            && area.contains(mouse_pos)
+        {
+            return Some([mouse_pos.x, mouse_pos.y]);
         }
         None
     }
@@ -103,5 +133,9 @@ impl Simulation {
         debug_assert_eq!(output.velocities.len(), count);
         debug_assert_eq!(output.speeds.len(), count);
         debug_assert_eq!(output.densities.len(), count);
+        debug_assert_eq!(
+            output.predator_velocities.len(),
+            output.predator_positions.len()
+        );
     }
 }
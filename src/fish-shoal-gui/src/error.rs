@@ -21,7 +21,7 @@ use std::{
 
 #[derive(Debug)]
 pub enum Error {
-    EFrame(String),
+    EFrame(eframe::Error),
 }
 
 impl Display for Error {
@@ -30,10 +30,16 @@ impl Display for Error {
             f,
             "Fish Shoal GUI error caused by {}",
             match self {
-                Self::EFrame(source) => format!("eframe: {source}"),
+                Self::EFrame(_) => "eframe",
             }
         )
     }
 }
 
-impl error::Error for Error {}
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::EFrame(source) => Some(source),
+        }
+    }
+}
@@ -16,8 +16,8 @@
 
 use crate::{Error, FocusedFishData, FocusedFishId, SideBar, Simulation};
 use eframe::{
-    egui::{Context, Vec2, ViewportBuilder}, App, CreationContext, Frame,
-    NativeOptions,
+    App, CreationContext, Frame, NativeOptions,
+    egui::{Context, Vec2, ViewportBuilder},
 };
 use egui::ThemePreference;
 use fish_shoal_simulator::{Config, SimulatorOutput};
@@ -26,9 +26,11 @@ use std::sync::mpsc::{Receiver, Sender};
 
 pub struct FishShoalGui {
     pub data_receiver: Receiver<SimulatorOutput>,
+    pub buffer_sender: Sender<SimulatorOutput>,
     pub config_sender: Sender<Config>,
     pub config: Config,
     pub screen: Vec2,
+    pub chunk_size: f32,
     pub focused_fish_id: Option<FocusedFishId>,
     pub focused_fish_data: Option<FocusedFishData>,
     pub old_mouse_pos: Option<[f32; 2]>,
@@ -39,12 +41,18 @@ pub struct FishShoalGui {
 }
 
 impl FishShoalGui {
-    pub fn new(data_receiver: Receiver<SimulatorOutput>, config_sender: Sender<Config>) -> Self {
+    pub fn new(
+        data_receiver: Receiver<SimulatorOutput>,
+        buffer_sender: Sender<SimulatorOutput>,
+        config_sender: Sender<Config>,
+    ) -> Self {
         Self {
             data_receiver,
+            buffer_sender,
             config_sender,
             config: Config::default(),
             screen: Vec2::default(),
+            chunk_size: 0.0,
             focused_fish_id: None,
             focused_fish_data: None,
             old_mouse_pos: None,
@@ -71,15 +79,16 @@ impl FishShoalGui {
                 Ok(Box::new(self))
             }),
         )
-        .map_err(|err| Error::EFrame(err.to_string()))
+        .map_err(Error::EFrame)
     }
 }
 
 impl App for FishShoalGui {
     fn update(&mut self, ctx: &Context, frame: &mut Frame) {
-        if self.config_sender.send(self.config).is_err() {
+        if self.config_sender.send(self.config.clone()).is_err() {
             return;
         }
+        self.config.steps = 0;
 
         SideBar::render(self, ctx, frame);
         Simulation::render(self, ctx, frame);
@@ -0,0 +1,76 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use fish_shoal_simulator::{Config, FishShoalSimulator};
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::time::{Duration, Instant};
+
+const SEED: u64 = 42;
+const ENTITY_COUNTS: [usize; 3] = [1_000, 5_000, 10_000];
+const WARMUP_TICKS: usize = 5;
+const MEASURED_TICKS: usize = 50;
+
+fn measure(pool: &ThreadPool, entity_count: usize) -> Duration {
+    pool.install(|| {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::builder()
+            .with_config(Config {
+                entity_count,
+                ..Config::default()
+            })
+            .with_seed(SEED)
+            .build()
+            .expect("failed to create simulator");
+        simulator
+            .step_n(WARMUP_TICKS)
+            .expect("failed to run simulator");
+
+        let start: Instant = Instant::now();
+        simulator
+            .step_n(MEASURED_TICKS)
+            .expect("failed to run simulator");
+        start.elapsed() / MEASURED_TICKS as u32
+    })
+}
+
+fn main() {
+    let single_thread: ThreadPool = ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .expect("failed to build single thread pool");
+    let all_threads: ThreadPool = ThreadPoolBuilder::new()
+        .build()
+        .expect("failed to build thread pool");
+
+    println!(
+        "{:>8} | {:>14} | {:>14} | {:>7}",
+        "fish",
+        "1 thread",
+        format!("{} threads", all_threads.current_num_threads()),
+        "speedup"
+    );
+
+    for entity_count in ENTITY_COUNTS {
+        let sequential: Duration = measure(&single_thread, entity_count);
+        let parallel: Duration = measure(&all_threads, entity_count);
+
+        println!(
+            "{entity_count:>8} | {:>11.2} ms | {:>11.2} ms | {:>6.2}x",
+            sequential.as_secs_f64() * 1_000.0,
+            parallel.as_secs_f64() * 1_000.0,
+            sequential.as_secs_f64() / parallel.as_secs_f64()
+        );
+    }
+}
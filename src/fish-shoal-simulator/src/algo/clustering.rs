@@ -0,0 +1,128 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{Chunks, Vec2};
+use shipyard::EntityId;
+use std::iter;
+
+const NO_INDEX: usize = usize::MAX;
+
+#[derive(Debug)]
+pub struct Clustering {
+    parents: Vec<usize>,
+    indices: Vec<usize>,
+}
+
+impl Clustering {
+    /// Labels each fish with the smallest identifier of its cluster, fish
+    /// closer than `radius` belonging to the same cluster.
+    pub fn label(
+        ids: &[EntityId],
+        fish_ids: &[usize],
+        positions: &[Vec2],
+        chunks: &Chunks,
+        radius: f32,
+    ) -> Vec<usize> {
+        let mut clustering: Self = Self {
+            parents: (0..ids.len()).collect(),
+            indices: Vec::new(),
+        };
+
+        for (index, id) in ids.iter().enumerate() {
+            let entity_index: usize = id.uindex();
+            if entity_index >= clustering.indices.len() {
+                clustering.indices.resize(entity_index + 1, NO_INDEX);
+            }
+            clustering.indices[entity_index] = index;
+        }
+
+        let radius_squared: f32 = radius * radius;
+        for (index, &pos) in positions.iter().enumerate() {
+            for chunk in
+                iter::once(chunks.load_chunk(&pos)).chain(chunks.load_neighbors(&pos, radius))
+            {
+                for &other_id in chunk {
+                    let Some(other) = clustering.index_of(ids, other_id) else {
+                        continue;
+                    };
+                    if other > index && pos.distance_squared(positions[other]) <= radius_squared {
+                        clustering.union(index, other);
+                    }
+                }
+            }
+        }
+
+        let mut smallest: Vec<usize> = vec![usize::MAX; ids.len()];
+        for (index, &fish_id) in fish_ids.iter().enumerate() {
+            let root: usize = clustering.find(index);
+            smallest[root] = smallest[root].min(fish_id);
+        }
+
+        (0..ids.len())
+            .map(|index| smallest[clustering.find(index)])
+            .collect()
+    }
+
+    fn index_of(&self, ids: &[EntityId], id: EntityId) -> Option<usize> {
+        match self.indices.get(id.uindex()) {
+            Some(&index) if index != NO_INDEX && ids[index] == id => Some(index),
+            _ => None,
+        }
+    }
+
+    fn find(&mut self, mut index: usize) -> usize {
+        while self.parents[index] != index {
+            self.parents[index] = self.parents[self.parents[index]];
+            index = self.parents[index];
+        }
+        index
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (root_a, root_b): (usize, usize) = (self.find(a), self.find(b));
+        if root_a != root_b {
+            self.parents[root_a.max(root_b)] = root_a.min(root_b);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clustering_label() {
+        let ids: Vec<EntityId> = (0..5)
+            .map(|index| EntityId::new_from_index_and_gen(index, 0))
+            .collect();
+        let fish_ids: Vec<usize> = vec![10, 11, 12, 13, 14];
+        let positions: Vec<Vec2> = vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(100.0, 0.0),
+            Vec2::new(8.0, 0.0),
+            Vec2::new(16.0, 0.0),
+            Vec2::new(105.0, 0.0),
+        ];
+
+        let mut chunks: Chunks = Chunks::new(10.0);
+        chunks.build(positions.iter().copied().zip(ids.iter().copied()));
+
+        assert_eq!(
+            Clustering::label(&ids, &fish_ids, &positions, &chunks, 10.0),
+            vec![10, 11, 10, 10, 11]
+        );
+    }
+}
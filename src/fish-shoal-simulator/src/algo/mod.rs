@@ -14,6 +14,8 @@
  * limitations under the License.
  */
 
+mod clustering;
 mod schooling_mechanism;
 
+pub use clustering::Clustering;
 pub use schooling_mechanism::SchoolingMechanism;
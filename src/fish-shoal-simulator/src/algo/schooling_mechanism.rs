@@ -14,36 +14,42 @@
 * limitations under the License.
 */
 
-use crate::{Scalar, Vec2};
-use shipyard::EntityId;
-use std::collections::HashMap;
+use crate::{Scalar, Threat, Vec2};
 
 // See ./docs/schooling_mechanism_in_fish.pdf
 #[derive(Debug)]
-pub struct SchoolingMechanism {
+pub struct SchoolingMechanism<'a> {
     position: Vec2,
     velocity: Vec2,
     speed: Scalar,
     stress: Scalar,
-    others_positions: HashMap<EntityId, Vec2>,
-    others_velocities: HashMap<EntityId, Vec2>,
-    // others_speeds: HashMap<EntityId, Scalar>,
+    others_positions: &'a [Vec2],
+    others_velocities: &'a [Vec2],
+    // others_speeds: &'a [Scalar],
     avoidance_radius: f32,
     alignment_radius: f32,
     attraction_radius: f32,
+    threats: &'a [Threat],
+    threat_radius: f32,
+    escape_speed: f32,
+    burst_speed: f32,
+    can_fast_start: bool,
+    fast_start: bool,
 }
 
-impl SchoolingMechanism {
+impl<'a> SchoolingMechanism<'a> {
     pub const MAX_NEIGHBORS: usize = 6;
+    pub const FLASH_EXPANSION_THREAT_SPEED: f32 = 150.0;
 
+    #[allow(clippy::too_many_arguments)]
     pub fn setup(
         position: Vec2,
         velocity: Vec2,
         speed: Scalar,
         stress: Scalar,
-        others_positions: HashMap<EntityId, Vec2>,
-        others_velocities: HashMap<EntityId, Vec2>,
-        // others_speeds: HashMap<EntityId, Scalar>,
+        others_positions: &'a [Vec2],
+        others_velocities: &'a [Vec2],
+        // others_speeds: &'a [Scalar],
         avoidance_radius: f32,
         alignment_radius: f32,
         attraction_radius: f32,
@@ -59,20 +65,91 @@ impl SchoolingMechanism {
             avoidance_radius,
             alignment_radius,
             attraction_radius,
+            threats: &[],
+            threat_radius: 0.0,
+            escape_speed: 0.0,
+            burst_speed: 0.0,
+            can_fast_start: false,
+            fast_start: false,
         }
     }
 
+    pub fn with_threats(
+        mut self,
+        threats: &'a [Threat],
+        threat_radius: f32,
+        escape_speed: f32,
+        burst_speed: f32,
+        can_fast_start: bool,
+    ) -> Self {
+        self.threats = threats;
+        self.threat_radius = threat_radius;
+        self.escape_speed = escape_speed;
+        self.burst_speed = burst_speed;
+        self.can_fast_start = can_fast_start;
+        self
+    }
+
+    pub fn is_fast_start(&self) -> bool {
+        self.fast_start
+    }
+
     pub fn set_behavior(&self, velocity: &mut Vec2, speed: &mut Scalar, stress: &mut Scalar) {
         *velocity = self.velocity;
         *speed = self.speed;
         *stress = self.stress;
     }
 
+    pub fn escape(&mut self) -> bool {
+        let Some(threat) = self
+            .threats
+            .iter()
+            .filter(|threat| self.position.distance(threat.position) <= self.threat_radius)
+            .min_by(|a, b| {
+                let dist_a: f32 = self.position.distance_squared(a.position);
+                let dist_b: f32 = self.position.distance_squared(b.position);
+                dist_a.total_cmp(&dist_b)
+            })
+        else {
+            return false;
+        };
+
+        let away: Vec2 = (self.position - threat.position).normalized();
+        let heading: Vec2 = threat.direction.normalized();
+
+        self.stress.value = 1.0;
+        self.speed.value = self.escape_speed;
+
+        if heading.dot(away) <= 0.0 {
+            self.velocity = away;
+            return true;
+        }
+
+        if threat.speed >= Self::FLASH_EXPANSION_THREAT_SPEED {
+            // Flash expansion: dart radially away from a fast attack.
+            self.velocity = away;
+        } else {
+            // Fountain effect: move aside and rejoin behind a slow approach.
+            let mut side: Vec2 = heading.perpendicular();
+            if side.dot(away) < 0.0 {
+                side = -side;
+            }
+            self.velocity = (side - heading * 0.5).normalized();
+        }
+
+        if self.can_fast_start {
+            self.speed.value = self.burst_speed;
+            self.fast_start = true;
+        }
+
+        true
+    }
+
     pub fn avoidance(&mut self) -> bool {
         let mut position_to_avoid: Vec2 = Vec2::ZERO;
 
         let mut count: f32 = 0.0;
-        for (_, &other_position) in &self.others_positions {
+        for &other_position in self.others_positions {
             if self.position.distance(other_position) <= self.avoidance_radius {
                 position_to_avoid += other_position;
                 count += 1.0;
@@ -97,12 +174,13 @@ impl SchoolingMechanism {
         let mut velocity_to_align: Vec2 = Vec2::ZERO;
 
         let mut count: f32 = 0.0;
-        for (other_id, &other_position) in &self.others_positions {
+        for (&other_position, &other_velocity) in
+            self.others_positions.iter().zip(self.others_velocities)
+        {
             if self.position.distance(other_position) <= self.avoidance_radius {
                 continue;
             }
             if self.position.distance(other_position) <= self.alignment_radius {
-                let other_velocity: Vec2 = self.others_velocities[other_id];
                 velocity_to_align += other_velocity;
                 count += 1.0;
                 velocity_to_align /= count;
@@ -126,7 +204,7 @@ impl SchoolingMechanism {
         let mut position_to_join: Vec2 = Vec2::ZERO;
 
         let mut count: f32 = 0.0;
-        for (_, &other_position) in &self.others_positions {
+        for &other_position in self.others_positions {
             let avoid: bool = self.position.distance(other_position) <= self.avoidance_radius;
             let align: bool = self.position.distance(other_position) <= self.alignment_radius;
             if avoid || align {
@@ -152,3 +230,116 @@ impl SchoolingMechanism {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::SchoolingMechanism;
+    use crate::{Scalar, Threat, Vec2};
+
+    fn mock_algo(threats: &[Threat], can_fast_start: bool) -> SchoolingMechanism<'_> {
+        SchoolingMechanism::setup(
+            Vec2::new(100.0, 100.0),
+            Vec2::new(1.0, 0.0),
+            Scalar::new(50.0),
+            Scalar::new(0.1),
+            &[],
+            &[],
+            10.0,
+            30.0,
+            50.0,
+        )
+        .with_threats(threats, 80.0, 100.0, 250.0, can_fast_start)
+    }
+
+    #[test]
+    fn schooling_mechanism_flash_expansion() {
+        let threats: [Threat; 1] = [Threat {
+            position: Vec2::new(50.0, 100.0),
+            direction: Vec2::new(1.0, 0.0),
+            speed: 200.0,
+        }];
+        let mut algo: SchoolingMechanism = mock_algo(&threats, true);
+
+        assert!(algo.escape());
+        assert!(algo.is_fast_start());
+
+        let (mut velocity, mut speed, mut stress): (Vec2, Scalar, Scalar) =
+            (Vec2::ZERO, Scalar::ZERO, Scalar::ZERO);
+        algo.set_behavior(&mut velocity, &mut speed, &mut stress);
+
+        assert_eq!(velocity, Vec2::new(1.0, 0.0));
+        assert_eq!(speed, Scalar::new(250.0));
+    }
+
+    #[test]
+    fn schooling_mechanism_fountain_effect() {
+        let threats: [Threat; 1] = [Threat {
+            position: Vec2::new(50.0, 90.0),
+            direction: Vec2::new(1.0, 0.0),
+            speed: 80.0,
+        }];
+        let mut algo: SchoolingMechanism = mock_algo(&threats, false);
+
+        assert!(algo.escape());
+        assert!(!algo.is_fast_start());
+
+        let (mut velocity, mut speed, mut stress): (Vec2, Scalar, Scalar) =
+            (Vec2::ZERO, Scalar::ZERO, Scalar::ZERO);
+        algo.set_behavior(&mut velocity, &mut speed, &mut stress);
+
+        assert!(velocity.x < 0.0, "Fish should swim back past the threat");
+        assert!(
+            velocity.y > 0.0,
+            "Fish should move aside of the threat path"
+        );
+        assert_eq!(speed, Scalar::new(100.0));
+    }
+
+    #[test]
+    fn schooling_mechanism_no_threat_in_range() {
+        let threats: [Threat; 1] = [Threat {
+            position: Vec2::new(500.0, 500.0),
+            direction: Vec2::new(1.0, 0.0),
+            speed: 200.0,
+        }];
+        let mut algo: SchoolingMechanism = mock_algo(&threats, true);
+
+        assert!(!algo.escape());
+        assert!(!algo.is_fast_start());
+    }
+
+    #[test]
+    fn schooling_mechanism_alignment() {
+        let others_positions: [Vec2; 3] = [
+            Vec2::new(120.0, 100.0),
+            Vec2::new(100.0, 120.0),
+            Vec2::new(140.0, 140.0),
+        ];
+        let others_velocities: [Vec2; 3] = [
+            Vec2::new(0.0, 1.0),
+            Vec2::new(0.0, 1.0),
+            Vec2::new(-1.0, 0.0),
+        ];
+        let mut algo: SchoolingMechanism = SchoolingMechanism::setup(
+            Vec2::new(100.0, 100.0),
+            Vec2::new(1.0, 0.0),
+            Scalar::new(50.0),
+            Scalar::new(0.1),
+            &others_positions,
+            &others_velocities,
+            10.0,
+            30.0,
+            50.0,
+        );
+
+        assert!(!algo.avoidance());
+        assert!(algo.alignment());
+
+        let (mut velocity, mut speed, mut stress): (Vec2, Scalar, Scalar) =
+            (Vec2::ZERO, Scalar::ZERO, Scalar::ZERO);
+        algo.set_behavior(&mut velocity, &mut speed, &mut stress);
+
+        assert_eq!(velocity, Vec2::new(0.0, 1.0));
+        assert_eq!(speed, Scalar::new(75.0));
+    }
+}
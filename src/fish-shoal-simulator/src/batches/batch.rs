@@ -0,0 +1,174 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    BatchResults, BatchRow, BatchRun, Config, Error, FishShoalSimulator, Metric, SimulatorOutput,
+};
+use rayon::{ThreadPool, ThreadPoolBuilder, prelude::*};
+
+#[derive(Debug, Default, Clone)]
+pub struct Batch {
+    runs: Vec<BatchRun>,
+    ticks: usize,
+    metrics: Vec<Metric>,
+    threads: Option<usize>,
+}
+
+impl Batch {
+    pub fn new(ticks: usize) -> Self {
+        Self {
+            ticks,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_run(mut self, config: Config, seed: u64) -> Self {
+        self.runs.push(BatchRun::new(config, seed));
+        self
+    }
+
+    pub fn with_seeds<I>(mut self, config: Config, seeds: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        self.runs.extend(
+            seeds
+                .into_iter()
+                .map(|seed: u64| BatchRun::new(config.clone(), seed)),
+        );
+        self
+    }
+
+    pub fn with_metric(mut self, metric: Metric) -> Self {
+        if !self.metrics.contains(&metric) {
+            self.metrics.push(metric);
+        }
+        self
+    }
+
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = Some(threads);
+        self
+    }
+
+    pub fn runs(&self) -> &[BatchRun] {
+        &self.runs
+    }
+
+    // Every run is independent and seeded, so results do not depend on the thread count.
+    pub fn run(&self) -> Result<BatchResults, Error> {
+        for run in &self.runs {
+            run.config.validate()?;
+        }
+
+        let pool: ThreadPool = ThreadPoolBuilder::new()
+            .num_threads(self.threads.unwrap_or(0))
+            .build()
+            .map_err(Error::ThreadPool)?;
+
+        let metrics: Vec<Metric> = if self.metrics.is_empty() {
+            Metric::ALL.to_vec()
+        } else {
+            self.metrics.clone()
+        };
+
+        let rows: Vec<BatchRow> = pool.install(|| {
+            self.runs
+                .par_iter()
+                .enumerate()
+                .map(|(index, run)| self.measure(index, run, &metrics))
+                .collect::<Result<Vec<BatchRow>, Error>>()
+        })?;
+
+        Ok(BatchResults { metrics, rows })
+    }
+
+    fn measure(&self, index: usize, run: &BatchRun, metrics: &[Metric]) -> Result<BatchRow, Error> {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::builder()
+            .with_config(run.config.clone())
+            .with_seed(run.seed)
+            .build()?;
+        simulator.step_n(self.ticks)?;
+
+        let output: SimulatorOutput = simulator.snapshot();
+
+        Ok(BatchRow {
+            run: index,
+            seed: run.seed,
+            values: metrics
+                .iter()
+                .map(|metric| metric.measure(&output))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Batch;
+    use crate::{BatchResults, Config, Error, Metric};
+
+    #[test]
+    fn batch_run() {
+        let cfg: Config = Config {
+            entity_count: 60,
+            ..Config::default()
+        };
+        let batch: Batch = Batch::new(10)
+            .with_seeds(cfg.clone(), [1, 2, 3])
+            .with_run(
+                Config {
+                    entity_count: 30,
+                    ..cfg
+                },
+                1,
+            )
+            .with_metric(Metric::FishCount)
+            .with_metric(Metric::Polarization);
+
+        let results: BatchResults = batch.clone().with_threads(2).run().unwrap();
+        let sequential: BatchResults = batch.with_threads(1).run().unwrap();
+
+        assert_eq!(results, sequential);
+        assert_eq!(
+            results.column(Metric::FishCount),
+            Some(vec![60.0, 60.0, 60.0, 30.0])
+        );
+        assert_eq!(results.column(Metric::MeanSpeed), None);
+
+        let mut csv: Vec<u8> = Vec::new();
+        results.write_csv(&mut csv).unwrap();
+        let csv: String = String::from_utf8(csv).unwrap();
+        assert!(csv.starts_with("run,seed,fish_count,polarization\n0,1,60,"));
+        assert_eq!(csv.lines().count(), 5);
+    }
+
+    #[test]
+    fn batch_invalid_run() {
+        let result: Result<BatchResults, Error> = Batch::new(1_000_000)
+            .with_run(Config::default(), 1)
+            .with_run(
+                Config {
+                    width: 0,
+                    ..Config::default()
+                },
+                1,
+            )
+            .run();
+
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
+}
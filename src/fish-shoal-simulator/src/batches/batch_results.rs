@@ -0,0 +1,49 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{BatchRow, Metric};
+use std::io::{self, Write};
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BatchResults {
+    pub metrics: Vec<Metric>,
+    pub rows: Vec<BatchRow>,
+}
+
+impl BatchResults {
+    pub fn column(&self, metric: Metric) -> Option<Vec<f64>> {
+        let index: usize = self.metrics.iter().position(|&other| other == metric)?;
+        Some(self.rows.iter().map(|row| row.values[index]).collect())
+    }
+
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "run,seed")?;
+        for metric in &self.metrics {
+            write!(writer, ",{}", metric.name())?;
+        }
+        writeln!(writer)?;
+
+        for row in &self.rows {
+            write!(writer, "{},{}", row.run, row.seed)?;
+            for value in &row.values {
+                write!(writer, ",{value}")?;
+            }
+            writeln!(writer)?;
+        }
+
+        writer.flush()
+    }
+}
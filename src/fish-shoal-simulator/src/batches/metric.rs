@@ -0,0 +1,119 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{SimulatorOutput, Vec2};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Metric {
+    FishCount,
+    MeanSpeed,
+    Polarization,
+    MeanDensity,
+    IsolatedFraction,
+    Dispersion,
+}
+
+impl Metric {
+    pub const ALL: [Self; 6] = [
+        Self::FishCount,
+        Self::MeanSpeed,
+        Self::Polarization,
+        Self::MeanDensity,
+        Self::IsolatedFraction,
+        Self::Dispersion,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::FishCount => "fish_count",
+            Self::MeanSpeed => "mean_speed",
+            Self::Polarization => "polarization",
+            Self::MeanDensity => "mean_density",
+            Self::IsolatedFraction => "isolated_fraction",
+            Self::Dispersion => "dispersion",
+        }
+    }
+
+    pub fn measure(&self, output: &SimulatorOutput) -> f64 {
+        let count: usize = output.ids.len();
+        if count == 0 {
+            return 0.0;
+        }
+
+        let mean = |sum: f64| sum / count as f64;
+
+        match self {
+            Self::FishCount => count as f64,
+            Self::MeanSpeed => mean(output.speeds.iter().map(|&speed| speed as f64).sum()),
+            Self::Polarization => {
+                let heading: Vec2 = output
+                    .velocities
+                    .iter()
+                    .map(|&vel| Vec2::from(vel).normalized())
+                    .fold(Vec2::ZERO, |sum, dir| sum + dir);
+                mean(heading.length() as f64)
+            }
+            Self::MeanDensity => mean(output.densities.iter().map(|&d| d as f64).sum()),
+            Self::IsolatedFraction => {
+                mean(output.densities.iter().filter(|&&d| d == 0).count() as f64)
+            }
+            Self::Dispersion => {
+                let centroid: Vec2 = output
+                    .positions
+                    .iter()
+                    .fold(Vec2::ZERO, |sum, &pos| sum + pos.into())
+                    / count as f32;
+                mean(
+                    output
+                        .positions
+                        .iter()
+                        .map(|&pos| centroid.distance(pos.into()) as f64)
+                        .sum(),
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Metric;
+    use crate::SimulatorOutput;
+
+    #[test]
+    fn metric_measure() {
+        let output: SimulatorOutput = SimulatorOutput {
+            ids: vec![0, 1, 2, 3],
+            positions: vec![[0.0, 0.0], [2.0, 0.0], [0.0, 2.0], [2.0, 2.0]],
+            velocities: vec![[1.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.0, -1.0]],
+            speeds: vec![10.0, 20.0, 30.0, 40.0],
+            densities: vec![0, 1, 2, 1],
+            ..SimulatorOutput::default()
+        };
+
+        let values: Vec<f64> = Metric::ALL
+            .iter()
+            .map(|metric| metric.measure(&output))
+            .collect();
+
+        assert_eq!(&values[..5], &[4.0, 25.0, 0.5, 1.0, 0.25]);
+        assert!((values[5] - 2.0_f64.sqrt()).abs() < 1e-6);
+        assert_eq!(
+            Metric::Polarization.measure(&SimulatorOutput::default()),
+            0.0
+        );
+    }
+}
@@ -0,0 +1,37 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use shipyard::Component;
+
+#[derive(Component, Debug, Default)]
+pub struct Escape {
+    pub refractory: f32,
+    pub fast_start: bool,
+}
+
+impl Escape {
+    pub fn is_ready(&self) -> bool {
+        self.refractory <= 0.0
+    }
+
+    pub fn trigger(&mut self) {
+        self.fast_start = true;
+    }
+
+    pub fn recover(&mut self, elapsed: f32) {
+        self.refractory = (self.refractory - elapsed).max(0.0);
+    }
+}
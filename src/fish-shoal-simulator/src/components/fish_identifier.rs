@@ -16,5 +16,5 @@
 
 use shipyard::Component;
 
-#[derive(Component, Debug)]
-pub struct FishIdentifier;
+#[derive(Component, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FishIdentifier(pub usize);
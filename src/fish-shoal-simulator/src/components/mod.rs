@@ -14,20 +14,28 @@
  * limitations under the License.
  */
 
-mod fish_identifier;
-mod social;
 mod density;
+mod escape;
+mod event_state;
+mod fish_identifier;
 mod position;
+mod predator_identifier;
+mod random_stream;
+mod social;
 mod speed;
 mod stress;
 mod target_speed;
 mod target_velocity;
 mod velocity;
 
-pub use fish_identifier::FishIdentifier;
-pub use social::Social;
 pub use density::Density;
+pub use escape::Escape;
+pub use event_state::EventState;
+pub use fish_identifier::FishIdentifier;
 pub use position::Position;
+pub use predator_identifier::PredatorIdentifier;
+pub use random_stream::RandomStream;
+pub use social::Social;
 pub use speed::Speed;
 pub use stress::Stress;
 pub use target_speed::TargetSpeed;
@@ -14,11 +14,13 @@
  * limitations under the License.
  */
 
+use crate::Rule;
 use shipyard::Component;
 
 #[derive(Component, Debug, Default)]
 pub struct Social {
     pub is_in_group: bool,
+    pub rule: Rule,
 }
 
 impl Social {
@@ -29,4 +31,8 @@ impl Social {
     pub fn set_alone(&mut self) {
         self.is_in_group = false;
     }
+
+    pub fn set_rule(&mut self, rule: Rule) {
+        self.rule = rule;
+    }
 }
@@ -0,0 +1,118 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::{
+    error,
+    fmt::{Display, Formatter, Result},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigError {
+    EmptyArea {
+        width: usize,
+        height: usize,
+    },
+    NonFinite {
+        field: &'static str,
+    },
+    NonPositive {
+        field: &'static str,
+        value: f32,
+    },
+    Negative {
+        field: &'static str,
+        value: f32,
+    },
+    TooLarge {
+        field: &'static str,
+        value: f32,
+        max: f32,
+    },
+    NoSubSteps,
+    Probability {
+        field: &'static str,
+        value: f64,
+    },
+    FieldOfView {
+        field: &'static str,
+        value: f32,
+    },
+    RadiusOrder {
+        avoidance: f32,
+        alignment: f32,
+        attraction: f32,
+    },
+    DegeneratePolygon {
+        vertices: usize,
+    },
+    FlatPolygon {
+        area: f32,
+    },
+    SpawnPattern(&'static str),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::EmptyArea { width, height } => {
+                write!(f, "the area {width}x{height} must not be empty")
+            }
+            Self::NonFinite { field } => write!(f, "{field} must be finite"),
+            Self::NonPositive { field, value } => {
+                write!(f, "{field} must be greater than zero, got {value}")
+            }
+            Self::Negative { field, value } => {
+                write!(
+                    f,
+                    "{field} must be a finite non-negative value, got {value}"
+                )
+            }
+            Self::TooLarge { field, value, max } => {
+                write!(f, "{field} must be at most {max}, got {value}")
+            }
+            Self::NoSubSteps => write!(f, "max_sub_steps must be at least one"),
+            Self::Probability { field, value } => {
+                write!(
+                    f,
+                    "{field} must be a probability between 0 and 1, got {value}"
+                )
+            }
+            Self::FieldOfView { field, value } => {
+                write!(f, "{field} must be between 0 and 360 degrees, got {value}")
+            }
+            Self::RadiusOrder {
+                avoidance,
+                alignment,
+                attraction,
+            } => write!(
+                f,
+                "radii must satisfy avoidance < alignment < attraction, got {avoidance} < {alignment} < {attraction}"
+            ),
+            Self::DegeneratePolygon { vertices } => {
+                write!(
+                    f,
+                    "a polygon arena needs at least 3 vertices, got {vertices}"
+                )
+            }
+            Self::FlatPolygon { area } => {
+                write!(f, "a polygon arena must enclose an area, got {area}")
+            }
+            Self::SpawnPattern(reason) => write!(f, "spawn_pattern: {reason}"),
+        }
+    }
+}
+
+impl error::Error for ConfigError {}
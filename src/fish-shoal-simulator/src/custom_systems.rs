@@ -0,0 +1,74 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::Stage;
+use shipyard::{IntoWorkloadSystem, Workload};
+use std::fmt::{Debug, Formatter, Result};
+
+#[derive(Default)]
+pub(crate) struct CustomSystems {
+    before: Vec<(Stage, Workload)>,
+    after: Vec<(Stage, Workload)>,
+}
+
+impl CustomSystems {
+    pub(crate) fn add_before<B, R, S>(&mut self, stage: Stage, system: S)
+    where
+        S: IntoWorkloadSystem<B, R>,
+    {
+        self.before.push((stage, Self::wrap(system)));
+    }
+
+    pub(crate) fn add_after<B, R, S>(&mut self, stage: Stage, system: S)
+    where
+        S: IntoWorkloadSystem<B, R>,
+    {
+        self.after.push((stage, Self::wrap(system)));
+    }
+
+    pub(crate) fn append_before(&mut self, stage: Stage, workload: Workload) -> Workload {
+        Self::append(&mut self.before, stage, workload)
+    }
+
+    pub(crate) fn append_after(&mut self, stage: Stage, workload: Workload) -> Workload {
+        Self::append(&mut self.after, stage, workload)
+    }
+
+    fn wrap<B, R, S>(system: S) -> Workload
+    where
+        S: IntoWorkloadSystem<B, R>,
+    {
+        Workload::new("custom").with_system(system)
+    }
+
+    fn append(systems: &mut [(Stage, Workload)], stage: Stage, workload: Workload) -> Workload {
+        systems
+            .iter_mut()
+            .filter(|(hook, _)| *hook == stage)
+            .fold(workload, |workload: Workload, (_, system)| {
+                workload.append(system)
+            })
+    }
+}
+
+impl Debug for CustomSystems {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("CustomSystems")
+            .field("before", &self.before.len())
+            .field("after", &self.after.len())
+            .finish()
+    }
+}
@@ -15,60 +15,162 @@
  */
 
 use crate::{
-    Config, FishIdentifier, Social, Density, Position, Scalar, Speed, Stress, TargetSpeed,
-    TargetVelocity, Vec2, Velocity,
+    Chunks, Config, Density, Escape, EventState, FishIdentifier, IdGenerator, Position,
+    RandomSource, RandomStream, RemovalPolicy, Scalar, Social, SpawnPattern, Speed, Stress,
+    TargetSpeed, TargetVelocity, Vec2, Velocity,
+};
+use rand::{rngs::StdRng, seq::SliceRandom};
+use shipyard::{EntityId, IntoIter, UniqueView, UniqueViewMut, View, World};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    iter,
+    ops::Range,
 };
-use rand::{rngs::ThreadRng, seq::SliceRandom};
-use shipyard::{EntityId, IntoIter, View, World};
 
 #[derive(Debug)]
 pub struct Fish;
 
 impl Fish {
-    pub fn add(world: &mut World, amount: usize, cfg: Config) {
-        let mut rng: ThreadRng = rand::rng();
-
-        for _ in 0..amount {
-            world.add_entity((
-                FishIdentifier,
-                Position(Vec2::new_random(
-                    &mut rng,
-                    0.0..cfg.width as f32,
-                    0.0..cfg.height as f32,
-                )),
-                Velocity(Vec2::random_dir(&mut rng)),
-                TargetVelocity(Vec2::random_dir(&mut rng)),
-                Speed(Scalar::ZERO),
-                TargetSpeed(Scalar::new_random(&mut rng, 50.0..100.0)),
-                Stress(Scalar::new(0.1)),
-                Density::default(),
-                Social::default(),
-            ));
+    pub fn add(world: &mut World, amount: usize, cfg: &Config) -> Range<usize> {
+        Self::add_pattern(world, amount, &cfg.spawn_pattern, cfg)
+    }
+
+    pub fn add_pattern(
+        world: &mut World,
+        amount: usize,
+        pattern: &SpawnPattern,
+        cfg: &Config,
+    ) -> Range<usize> {
+        let origin: Vec2 = cfg.origin.into();
+        let width: f32 = cfg.width as f32;
+        let height: f32 = cfg.height as f32;
+
+        let mut rng: StdRng = world.run(|mut source: UniqueViewMut<RandomSource>| source.fork());
+        let placements: Vec<(Vec2, Vec2)> =
+            pattern.placements(&mut rng, amount, &cfg.arena, width, height);
+
+        let fish_ids: Range<usize> =
+            world.run(|mut generator: UniqueViewMut<IdGenerator>| generator.reserve(amount));
+
+        for (fish_id, (position, direction)) in fish_ids.clone().zip(placements) {
+            Self::insert(world, fish_id, origin + position, direction, &mut rng);
         }
+
+        fish_ids
     }
 
-    pub fn remove(world: &mut World, amount: usize) {
-        let maybe_ids: Option<Vec<EntityId>> = world.run(|fish: View<FishIdentifier>| {
-            let ids: Vec<EntityId> = (&fish).iter().with_id().map(|(id, _)| id).collect();
+    // Positions are relative to the area origin, like the ones of spawn patterns.
+    pub fn add_at(world: &mut World, fish: &[(Vec2, Vec2)], cfg: &Config) {
+        let origin: Vec2 = cfg.origin.into();
+        let mut rng: StdRng = world.run(|mut source: UniqueViewMut<RandomSource>| source.fork());
 
-            if ids.is_empty() {
-                return None;
-            }
+        let fish_ids: Range<usize> =
+            world.run(|mut generator: UniqueViewMut<IdGenerator>| generator.reserve(fish.len()));
 
-            let mut rng: ThreadRng = rand::rng();
-            let count: usize = amount.min(ids.len());
+        for (fish_id, &(position, direction)) in fish_ids.zip(fish) {
+            let direction: Vec2 = if direction.length() > 0.0 {
+                direction.normalized()
+            } else {
+                Vec2::random_dir(&mut rng)
+            };
+            Self::insert(world, fish_id, origin + position, direction, &mut rng);
+        }
+    }
 
-            let mut indices: Vec<usize> = (0..ids.len()).collect();
-            indices.shuffle(&mut rng);
-            let chosen: Vec<EntityId> = indices.into_iter().take(count).map(|i| ids[i]).collect();
+    fn insert(
+        world: &mut World,
+        fish_id: usize,
+        position: Vec2,
+        direction: Vec2,
+        rng: &mut StdRng,
+    ) {
+        let id: EntityId = world.add_entity((
+            FishIdentifier(fish_id),
+            Position(position),
+            Velocity(direction),
+            TargetVelocity(Vec2::random_dir(rng)),
+            Speed(Scalar::ZERO),
+            TargetSpeed(Scalar::new_random(rng, 50.0..100.0)),
+            Stress(Scalar::new(0.1)),
+            Density::default(),
+            Social::default(),
+            Escape::default(),
+        ));
+        world.add_component(id, (RandomStream::new(rng), EventState::default()));
+    }
+
+    pub fn remove(world: &mut World, amount: usize, policy: &RemovalPolicy) -> usize {
+        let chosen: Vec<EntityId> = world.run(
+            |fish: View<FishIdentifier>,
+             positions: View<Position>,
+             cfg: UniqueView<Config>,
+             mut source: UniqueViewMut<RandomSource>| {
+                let mut candidates: Vec<(EntityId, usize, Vec2)> = (&fish, &positions)
+                    .iter()
+                    .with_id()
+                    .map(|(id, (fish, pos))| (id, fish.0, pos.0))
+                    .collect();
 
-            Some(chosen)
-        });
+                match policy {
+                    RemovalPolicy::Random => candidates.shuffle(&mut source.fork()),
+                    RemovalPolicy::Oldest => candidates.sort_by_key(|&(_, fish_id, ..)| fish_id),
+                    RemovalPolicy::Newest => {
+                        candidates.sort_by_key(|&(_, fish_id, ..)| Reverse(fish_id))
+                    }
+                    RemovalPolicy::Region { min, max } => {
+                        candidates.retain(|&(_, _, pos)| {
+                            pos.x >= min.x && pos.x <= max.x && pos.y >= min.y && pos.y <= max.y
+                        });
+                        candidates.shuffle(&mut source.fork());
+                    }
+                    RemovalPolicy::Ids(ids) => {
+                        let ids: HashSet<usize> = ids.iter().copied().collect();
+                        candidates.retain(|(_, fish_id, ..)| ids.contains(fish_id));
+                    }
+                    RemovalPolicy::MostIsolated => {
+                        // Densities are only updated by Swarming, so they are counted
+                        // from the current positions to rank fish spawned this tick too.
+                        let densities: Vec<usize> =
+                            Self::densities(&candidates, cfg.alignment_radius);
+                        let mut ranked: Vec<(usize, (EntityId, usize, Vec2))> =
+                            densities.into_iter().zip(candidates).collect();
+                        ranked.sort_by_key(|&(density, (_, fish_id, _))| (density, fish_id));
+                        candidates = ranked.into_iter().map(|(_, candidate)| candidate).collect();
+                    }
+                }
 
-        if let Some(ids) = maybe_ids {
-            for id in ids {
-                let _ = world.delete_entity(id);
-            }
+                candidates
+                    .into_iter()
+                    .take(amount)
+                    .map(|(id, ..)| id)
+                    .collect()
+            },
+        );
+
+        for &id in &chosen {
+            let _ = world.delete_entity(id);
         }
+
+        chosen.len()
+    }
+
+    fn densities(candidates: &[(EntityId, usize, Vec2)], radius: f32) -> Vec<usize> {
+        let mut chunks: Chunks = Chunks::new(radius);
+        chunks.build(candidates.iter().map(|&(id, _, pos)| (pos, id)));
+
+        let positions: HashMap<EntityId, Vec2> =
+            candidates.iter().map(|&(id, _, pos)| (id, pos)).collect();
+
+        candidates
+            .iter()
+            .map(|&(id, _, pos)| {
+                iter::once(chunks.load_chunk(&pos))
+                    .chain(chunks.load_neighbors(&pos, radius))
+                    .flatten()
+                    .filter(|&&other| other != id && pos.distance(positions[&other]) <= radius)
+                    .count()
+            })
+            .collect()
     }
 }
@@ -15,5 +15,7 @@
  */
 
 mod fish;
+mod predator;
 
 pub use fish::Fish;
+pub use predator::Predator;
@@ -0,0 +1,56 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{Config, Position, PredatorIdentifier, RandomSource, Scalar, Speed, Vec2, Velocity};
+use rand::rngs::StdRng;
+use shipyard::{EntityId, IntoIter, UniqueViewMut, View, World};
+
+#[derive(Debug)]
+pub struct Predator;
+
+impl Predator {
+    pub fn add(world: &mut World, amount: usize, cfg: &Config) {
+        let origin: Vec2 = cfg.origin.into();
+        let width: f32 = cfg.width as f32;
+        let height: f32 = cfg.height as f32;
+
+        let mut rng: StdRng = world.run(|mut source: UniqueViewMut<RandomSource>| source.fork());
+
+        for _ in 0..amount {
+            world.add_entity((
+                PredatorIdentifier,
+                Position(origin + cfg.arena.random_position(&mut rng, width, height)),
+                Velocity(Vec2::random_dir(&mut rng)),
+                Speed(Scalar::new(cfg.predator_speed)),
+            ));
+        }
+    }
+
+    pub fn remove(world: &mut World, amount: usize) {
+        let ids: Vec<EntityId> = world.run(|predators: View<PredatorIdentifier>| {
+            (&predators)
+                .iter()
+                .with_id()
+                .map(|(id, _)| id)
+                .take(amount)
+                .collect()
+        });
+
+        for id in ids {
+            let _ = world.delete_entity(id);
+        }
+    }
+}
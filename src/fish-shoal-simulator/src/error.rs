@@ -14,16 +14,26 @@
  * limitations under the License.
  */
 
+use crate::ConfigError;
+use rayon::ThreadPoolBuildError;
+use shipyard::error::{AddWorkload, RunWorkload};
 use std::{
     error,
     fmt::{Display, Formatter, Result},
+    io,
+    path::PathBuf,
 };
 
 #[derive(Debug)]
 pub enum Error {
-    Create(String),
-    Config(String),
-    Run(String),
+    Create(AddWorkload),
+    InvalidFish { index: usize },
+    Config(ConfigError),
+    Run(RunWorkload),
+    ScenarioIo { path: PathBuf, source: io::Error },
+    ScenarioSyntax { line: usize, reason: String },
+    ScenarioEvent { index: usize, source: ConfigError },
+    ThreadPool(ThreadPoolBuildError),
 }
 
 impl Display for Error {
@@ -32,12 +42,39 @@ impl Display for Error {
             f,
             "Fish Shoal Simulator failed to {}",
             match self {
-                Self::Create(err) => format!("create: {err}"),
-                Self::Config(err) => format!("config: {err}"),
-                Self::Run(err) => format!("run: {err}"),
+                Self::Create(_) => "create its workload".to_string(),
+                Self::InvalidFish { index } => format!("create fish {index}: non-finite state"),
+                Self::Config(_) => "apply an invalid config".to_string(),
+                Self::Run(_) => "run a tick".to_string(),
+                Self::ScenarioIo { path, .. } => format!("read scenario {}", path.display()),
+                Self::ScenarioSyntax { line, reason } => {
+                    format!("load scenario: line {line}: {reason}")
+                }
+                Self::ScenarioEvent { index, .. } => {
+                    format!("apply scenario event {index}: invalid config")
+                }
+                Self::ThreadPool(_) => "build the batch thread pool".to_string(),
             }
         )
     }
 }
 
-impl error::Error for Error {}
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Create(source) => Some(source),
+            Self::Config(source) => Some(source),
+            Self::Run(source) => Some(source),
+            Self::ScenarioIo { source, .. } => Some(source),
+            Self::ScenarioEvent { source, .. } => Some(source),
+            Self::ThreadPool(source) => Some(source),
+            Self::InvalidFish { .. } | Self::ScenarioSyntax { .. } => None,
+        }
+    }
+}
+
+impl From<ConfigError> for Error {
+    fn from(err: ConfigError) -> Self {
+        Self::Config(err)
+    }
+}
@@ -15,18 +15,27 @@
  */
 
 mod algo;
+mod batches;
 mod components;
+mod config_error;
+mod custom_systems;
 mod entities;
 mod error;
+mod scenarios;
 mod simulator;
+mod simulator_builder;
 mod simulator_output;
 mod systems;
 mod types;
 mod uniques;
 
+pub use batches::*;
 pub use components::*;
+pub use config_error::ConfigError;
 pub use error::Error;
+pub use scenarios::*;
 pub use simulator::FishShoalSimulator;
+pub use simulator_builder::SimulatorBuilder;
 pub use simulator_output::SimulatorOutput;
 pub use types::*;
 pub use uniques::*;
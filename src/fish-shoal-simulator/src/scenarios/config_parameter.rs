@@ -0,0 +1,159 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::Config;
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConfigParameter {
+    TimeStep,
+    TimeScale,
+    Width,
+    Height,
+    EntityCount,
+    PredatorCount,
+    PredatorSpeed,
+    DirectionChangeProb,
+    SpeedChangeProb,
+    StressChangeProb,
+    AttractionFov,
+    AlignmentFov,
+    AttractionRadius,
+    AlignmentRadius,
+    AvoidanceRadius,
+    ThreatRadius,
+    EscapeSpeed,
+    BurstSpeed,
+    RefractoryPeriod,
+}
+
+impl ConfigParameter {
+    pub const ALL: [Self; 19] = [
+        Self::TimeStep,
+        Self::TimeScale,
+        Self::Width,
+        Self::Height,
+        Self::EntityCount,
+        Self::PredatorCount,
+        Self::PredatorSpeed,
+        Self::DirectionChangeProb,
+        Self::SpeedChangeProb,
+        Self::StressChangeProb,
+        Self::AttractionFov,
+        Self::AlignmentFov,
+        Self::AttractionRadius,
+        Self::AlignmentRadius,
+        Self::AvoidanceRadius,
+        Self::ThreatRadius,
+        Self::EscapeSpeed,
+        Self::BurstSpeed,
+        Self::RefractoryPeriod,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::TimeStep => "time_step",
+            Self::TimeScale => "time_scale",
+            Self::Width => "width",
+            Self::Height => "height",
+            Self::EntityCount => "entity_count",
+            Self::PredatorCount => "predator_count",
+            Self::PredatorSpeed => "predator_speed",
+            Self::DirectionChangeProb => "direction_change_prob",
+            Self::SpeedChangeProb => "speed_change_prob",
+            Self::StressChangeProb => "stress_change_prob",
+            Self::AttractionFov => "attraction_fov",
+            Self::AlignmentFov => "alignment_fov",
+            Self::AttractionRadius => "attraction_radius",
+            Self::AlignmentRadius => "alignment_radius",
+            Self::AvoidanceRadius => "avoidance_radius",
+            Self::ThreatRadius => "threat_radius",
+            Self::EscapeSpeed => "escape_speed",
+            Self::BurstSpeed => "burst_speed",
+            Self::RefractoryPeriod => "refractory_period",
+        }
+    }
+
+    pub fn get(self, cfg: &Config) -> f64 {
+        match self {
+            Self::TimeStep => cfg.time_step as f64,
+            Self::TimeScale => cfg.time_scale as f64,
+            Self::Width => cfg.width as f64,
+            Self::Height => cfg.height as f64,
+            Self::EntityCount => cfg.entity_count as f64,
+            Self::PredatorCount => cfg.predator_count as f64,
+            Self::PredatorSpeed => cfg.predator_speed as f64,
+            Self::DirectionChangeProb => cfg.direction_change_prob,
+            Self::SpeedChangeProb => cfg.speed_change_prob,
+            Self::StressChangeProb => cfg.stress_change_prob,
+            Self::AttractionFov => cfg.attraction_fov as f64,
+            Self::AlignmentFov => cfg.alignment_fov as f64,
+            Self::AttractionRadius => cfg.attraction_radius as f64,
+            Self::AlignmentRadius => cfg.alignment_radius as f64,
+            Self::AvoidanceRadius => cfg.avoidance_radius as f64,
+            Self::ThreatRadius => cfg.threat_radius as f64,
+            Self::EscapeSpeed => cfg.escape_speed as f64,
+            Self::BurstSpeed => cfg.burst_speed as f64,
+            Self::RefractoryPeriod => cfg.refractory_period as f64,
+        }
+    }
+
+    pub fn set(self, cfg: &mut Config, value: f64) {
+        let count: usize = value.max(0.0).round() as usize;
+
+        match self {
+            Self::TimeStep => cfg.time_step = value as f32,
+            Self::TimeScale => cfg.time_scale = value.max(0.0) as f32,
+            Self::Width => cfg.width = count,
+            Self::Height => cfg.height = count,
+            Self::EntityCount => cfg.entity_count = count,
+            Self::PredatorCount => cfg.predator_count = count,
+            Self::PredatorSpeed => cfg.predator_speed = value as f32,
+            Self::DirectionChangeProb => cfg.direction_change_prob = value.clamp(0.0, 1.0),
+            Self::SpeedChangeProb => cfg.speed_change_prob = value.clamp(0.0, 1.0),
+            Self::StressChangeProb => cfg.stress_change_prob = value.clamp(0.0, 1.0),
+            Self::AttractionFov => cfg.attraction_fov = value as f32,
+            Self::AlignmentFov => cfg.alignment_fov = value as f32,
+            Self::AttractionRadius => cfg.attraction_radius = value as f32,
+            Self::AlignmentRadius => cfg.alignment_radius = value as f32,
+            Self::AvoidanceRadius => cfg.avoidance_radius = value as f32,
+            Self::ThreatRadius => cfg.threat_radius = value as f32,
+            Self::EscapeSpeed => cfg.escape_speed = value as f32,
+            Self::BurstSpeed => cfg.burst_speed = value as f32,
+            Self::RefractoryPeriod => cfg.refractory_period = value as f32,
+        }
+    }
+}
+
+impl Display for ConfigParameter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for ConfigParameter {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|parameter: &Self| parameter.name() == value)
+            .ok_or_else(|| format!("unknown config parameter \"{value}\""))
+    }
+}
@@ -0,0 +1,25 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+mod config_parameter;
+mod scenario;
+mod scenario_action;
+mod scenario_event;
+
+pub use config_parameter::ConfigParameter;
+pub use scenario::Scenario;
+pub use scenario_action::ScenarioAction;
+pub use scenario_event::ScenarioEvent;
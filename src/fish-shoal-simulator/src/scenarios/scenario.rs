@@ -0,0 +1,217 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{Error, ScenarioAction, ScenarioEvent};
+use std::{fs, path::Path, str::FromStr, time::Duration};
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Scenario {
+    events: Vec<ScenarioEvent>,
+    cursor: usize,
+}
+
+impl Scenario {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path: &Path = path.as_ref();
+
+        fs::read_to_string(path)
+            .map_err(|source| Error::ScenarioIo {
+                path: path.to_path_buf(),
+                source,
+            })?
+            .parse()
+    }
+
+    pub fn at(mut self, time: Duration, action: ScenarioAction) -> Self {
+        self.push(ScenarioEvent::new(time, action));
+        self
+    }
+
+    pub fn push(&mut self, event: ScenarioEvent) {
+        let idx: usize = self
+            .events
+            .partition_point(|other| other.time <= event.time);
+        self.events.insert(idx, event);
+    }
+
+    pub fn events(&self) -> &[ScenarioEvent] {
+        &self.events
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.cursor >= self.events.len()
+    }
+
+    pub fn rewind(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub(crate) fn next_due(&mut self, elapsed: Duration) -> Option<(usize, ScenarioEvent)> {
+        let event: ScenarioEvent = *self.events.get(self.cursor)?;
+
+        if event.time > elapsed {
+            return None;
+        }
+
+        self.cursor += 1;
+        Some((self.cursor - 1, event))
+    }
+
+    fn parse_line(line: &str) -> Result<ScenarioEvent, String> {
+        let (time, action): (&str, &str) = line
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| "expected \"<seconds> <action> [arguments]\"".to_string())?;
+
+        let time: Duration = time
+            .parse::<f64>()
+            .ok()
+            .and_then(|seconds: f64| Duration::try_from_secs_f64(seconds).ok())
+            .ok_or_else(|| format!("invalid time \"{time}\""))?;
+
+        Ok(ScenarioEvent::new(time, action.parse()?))
+    }
+}
+
+impl FromStr for Scenario {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut scenario: Self = Self::new();
+
+        for (idx, line) in value.lines().enumerate() {
+            let line: &str = match line.split_once('#') {
+                Some((content, _)) => content.trim(),
+                None => line.trim(),
+            };
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let event: ScenarioEvent =
+                Self::parse_line(line).map_err(|reason: String| Error::ScenarioSyntax {
+                    line: idx + 1,
+                    reason,
+                })?;
+            scenario.push(event);
+        }
+
+        Ok(scenario)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scenario;
+    use crate::{Config, ConfigParameter, Error, ScenarioAction, ScenarioEvent};
+    use std::time::Duration;
+
+    #[test]
+    fn scenario_parse() {
+        let source: &str = "
+            # time  action
+            90 despawn_fraction 0.5
+            30 spawn 10   # new fish
+            60 scale direction_change_prob 2
+        ";
+
+        let scenario: Scenario = source.parse().expect("Scenario should parse");
+
+        let times: Vec<u64> = scenario
+            .events()
+            .iter()
+            .map(|event| event.time.as_secs())
+            .collect();
+        assert_eq!(times, vec![30, 60, 90]);
+
+        assert_eq!(scenario.events()[0].action, ScenarioAction::Spawn(10));
+        assert_eq!(
+            scenario.events()[1].action,
+            ScenarioAction::Scale(ConfigParameter::DirectionChangeProb, 2.0)
+        );
+        assert_eq!(
+            scenario.events()[2].action,
+            ScenarioAction::DespawnFraction(0.5)
+        );
+    }
+
+    #[test]
+    fn scenario_parse_errors() {
+        assert!("30".parse::<Scenario>().is_err());
+        assert!("-1 spawn 10".parse::<Scenario>().is_err());
+        assert!("30 fly 10".parse::<Scenario>().is_err());
+        assert!("30 spawn ten".parse::<Scenario>().is_err());
+        assert!("30 set unknown 1".parse::<Scenario>().is_err());
+        assert!("30 spawn 10 20".parse::<Scenario>().is_err());
+
+        assert!(matches!(
+            "30 spawn 10\n\n60 fly".parse::<Scenario>(),
+            Err(Error::ScenarioSyntax { line: 3, .. })
+        ));
+        assert!(matches!(
+            Scenario::load("missing.scenario"),
+            Err(Error::ScenarioIo { .. })
+        ));
+    }
+
+    #[test]
+    fn scenario_next_due() {
+        let mut scenario: Scenario = Scenario::new()
+            .at(Duration::from_secs(60), ScenarioAction::Despawn(5))
+            .at(Duration::from_secs(30), ScenarioAction::Spawn(5));
+
+        assert_eq!(scenario.next_due(Duration::from_secs(10)), None);
+        assert_eq!(
+            scenario.next_due(Duration::from_secs(45)),
+            Some((
+                0,
+                ScenarioEvent::new(Duration::from_secs(30), ScenarioAction::Spawn(5))
+            ))
+        );
+        assert_eq!(scenario.next_due(Duration::from_secs(45)), None);
+        assert_eq!(
+            scenario.next_due(Duration::from_secs(60)),
+            Some((
+                1,
+                ScenarioEvent::new(Duration::from_secs(60), ScenarioAction::Despawn(5))
+            ))
+        );
+        assert!(scenario.is_finished());
+
+        scenario.rewind();
+        assert!(!scenario.is_finished());
+    }
+
+    #[test]
+    fn scenario_action_apply() {
+        let mut cfg: Config = Config::default();
+        let entity_count: usize = cfg.entity_count;
+        let direction_change_prob: f64 = cfg.direction_change_prob;
+
+        ScenarioAction::Spawn(100).apply(&mut cfg);
+        assert_eq!(cfg.entity_count, entity_count + 100);
+
+        ScenarioAction::DespawnFraction(0.5).apply(&mut cfg);
+        assert_eq!(cfg.entity_count, (entity_count + 100) / 2);
+
+        ScenarioAction::Scale(ConfigParameter::DirectionChangeProb, 2.0).apply(&mut cfg);
+        assert_eq!(cfg.direction_change_prob, direction_change_prob * 2.0);
+    }
+}
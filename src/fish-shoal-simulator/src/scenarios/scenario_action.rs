@@ -0,0 +1,84 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{Config, ConfigParameter};
+use std::str::{FromStr, SplitWhitespace};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ScenarioAction {
+    Set(ConfigParameter, f64),
+    Scale(ConfigParameter, f64),
+    Spawn(usize),
+    Despawn(usize),
+    DespawnFraction(f64),
+}
+
+impl ScenarioAction {
+    pub fn apply(self, cfg: &mut Config) {
+        match self {
+            Self::Set(parameter, value) => parameter.set(cfg, value),
+            Self::Scale(parameter, factor) => parameter.set(cfg, parameter.get(cfg) * factor),
+            Self::Spawn(amount) => cfg.entity_count += amount,
+            Self::Despawn(amount) => cfg.entity_count = cfg.entity_count.saturating_sub(amount),
+            Self::DespawnFraction(fraction) => {
+                let amount: usize =
+                    (cfg.entity_count as f64 * fraction.clamp(0.0, 1.0)).round() as usize;
+                cfg.entity_count -= amount;
+            }
+        }
+    }
+
+    fn next_arg<T: FromStr>(args: &mut SplitWhitespace, name: &str) -> Result<T, String> {
+        let arg: &str = args
+            .next()
+            .ok_or_else(|| format!("missing {name} argument"))?;
+
+        arg.parse()
+            .map_err(|_| format!("invalid {name} argument \"{arg}\""))
+    }
+}
+
+impl FromStr for ScenarioAction {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut args: SplitWhitespace = value.split_whitespace();
+
+        let action: Self = match args.next() {
+            Some("set") => Self::Set(
+                Self::next_arg(&mut args, "parameter")?,
+                Self::next_arg(&mut args, "value")?,
+            ),
+            Some("scale") => Self::Scale(
+                Self::next_arg(&mut args, "parameter")?,
+                Self::next_arg(&mut args, "factor")?,
+            ),
+            Some("spawn") => Self::Spawn(Self::next_arg(&mut args, "amount")?),
+            Some("despawn") => Self::Despawn(Self::next_arg(&mut args, "amount")?),
+            Some("despawn_fraction") => {
+                Self::DespawnFraction(Self::next_arg(&mut args, "fraction")?)
+            }
+            Some(unknown) => return Err(format!("unknown action \"{unknown}\"")),
+            None => return Err("missing action".to_string()),
+        };
+
+        if let Some(extra) = args.next() {
+            return Err(format!("unexpected argument \"{extra}\""));
+        }
+
+        Ok(action)
+    }
+}
@@ -15,100 +15,1438 @@
  */
 
 use crate::{
-    entities::Fish, systems::*, Chunks, Config, DeltaTime, Density, Error, Position, SimulatorOutput,
-    Speed, Velocity,
+    Chunks, Clock, Config, ConfigError, DeltaTime, Density, EnabledSystems, Error, Escape,
+    EventKind, Events, FishIdentifier, FishState, IdGenerator, OutputField, Position,
+    PredatorIdentifier, RandomSource, RemovalPolicy, Rule, Scalar, Scenario, ScenarioEvent,
+    SimulationEvent, SimulationSystem, SimulatorBuilder, SimulatorOutput, Social, SpawnPattern,
+    Speed, Stage, Stress, TargetSpeed, TargetVelocity, Threats, Vec2, Velocity,
+    custom_systems::CustomSystems,
+    entities::{Fish, Predator},
+    systems::*,
 };
+use rand::rngs::StdRng;
 use shipyard::{
-    error::{AddWorkload, RunWorkload},
-    Workload,
-    {UniqueView, UniqueViewMut, View, World},
+    EntityId, Get, IntoIter, SystemModificator, Workload,
+    {UniqueView, UniqueViewMut, View, ViewMut, World},
 };
-use std::{cmp::Ordering, mem};
+use std::{cmp::Ordering, collections::HashSet, mem, ops::Range, time::Duration};
 
 #[derive(Debug)]
 pub struct FishShoalSimulator {
     world: World,
     paused: bool,
+    io_cfg: Config,
+    scenario: Option<Scenario>,
+    output: SimulatorOutput,
+    output_fields: HashSet<OutputField>,
 }
 
 impl FishShoalSimulator {
     pub fn new() -> Result<Self, Error> {
+        Self::builder().build()
+    }
+
+    pub fn with_seed(seed: u64) -> Result<Self, Error> {
+        Self::builder().with_seed(seed).build()
+    }
+
+    pub fn builder() -> SimulatorBuilder {
+        SimulatorBuilder::default()
+    }
+
+    pub(crate) fn create(
+        cfg: Config,
+        seed: u64,
+        fish: &[(Vec2, Vec2)],
+        systems: EnabledSystems,
+        events: Events,
+        mut custom: CustomSystems,
+        scenario: Option<Scenario>,
+    ) -> Result<Self, Error> {
         let mut world: World = World::default();
-        let cfg: Config = Config::default();
 
-        world.add_unique(Config::default());
-        world.add_unique(DeltaTime::default());
+        world.add_unique(cfg.clone());
+        world.add_unique(RandomSource::new(seed));
+        world.add_unique(IdGenerator::default());
+        world.add_unique(DeltaTime::new(cfg.time_step));
+        world.add_unique(Clock::default());
         world.add_unique(Chunks::new(cfg.attraction_radius));
+        world.add_unique(Threats::default());
+        world.add_unique(systems);
+        world.add_unique(events);
 
-        Fish::add(&mut world, cfg.entity_count, cfg);
+        Fish::add_at(&mut world, fish, &cfg);
+        Fish::add(
+            &mut world,
+            cfg.entity_count.saturating_sub(fish.len()),
+            &cfg,
+        );
+        Predator::add(&mut world, cfg.predator_count, &cfg);
 
-        Workload::new("sim")
-            .with_system(CalculateDeltaTime::system)
-            .with_system(LoadChunks::system)
-            .with_barrier()
-            .with_system(Motion::system)
-            .with_system(OutOfBound::system)
-            .with_system(LerpToTarget::system)
-            .with_system(RandomBehavior::system)
-            .with_system(Swarming::system)
+        Self::workload(&mut custom)
             .add_to_world(&world)
-            .map_err(|err: AddWorkload| Error::Create(err.to_string()))?;
+            .map_err(Error::Create)?;
 
         Ok(Self {
             world,
-            paused: false,
+            paused: cfg.paused,
+            io_cfg: Config::default(),
+            scenario,
+            output: SimulatorOutput::default(),
+            output_fields: OutputField::DEFAULT.into_iter().collect(),
         })
     }
 
+    fn workload(custom: &mut CustomSystems) -> Workload {
+        let mut workload: Workload = Workload::new("sim")
+            .with_system(CalculateDeltaTime::system)
+            .with_system(AdvanceClock::system)
+            .with_system(LoadChunks::system)
+            .with_barrier();
+
+        for stage in Stage::ALL {
+            workload = custom.append_before(stage, workload);
+            workload = match stage {
+                Stage::Motion => workload.with_system(Motion::system),
+                Stage::Boundary => workload.with_system(
+                    OutOfBound::system.run_if(Self::enabled(SimulationSystem::Boundary)),
+                ),
+                Stage::Behavior => workload
+                    .with_system(LerpToTarget::system)
+                    .with_system(
+                        RandomBehavior::system
+                            .run_if(Self::enabled(SimulationSystem::RandomBehavior)),
+                    )
+                    .with_system(Hunt::system.run_if(Self::enabled(SimulationSystem::Hunt)))
+                    .with_system(DetectThreats::system)
+                    .with_system(Swarming::system.run_if(Self::enabled(SimulationSystem::Swarming)))
+                    .with_system(FastStart::system.run_if(Self::enabled(SimulationSystem::Escape)))
+                    .with_system(EmitEvents::system),
+            };
+            workload = custom.append_after(stage, workload);
+        }
+
+        workload
+    }
+
+    fn enabled(
+        system: SimulationSystem,
+    ) -> impl Fn(UniqueView<EnabledSystems>) -> bool + Send + Sync + 'static {
+        move |systems: UniqueView<EnabledSystems>| systems.is_enabled(system)
+    }
+
     pub fn run<F>(&mut self, mut io: F) -> Result<(), Error>
     where
-        F: FnMut(SimulatorOutput) -> Config + 'static,
+        F: FnMut(SimulatorOutput) -> Config,
     {
+        self.advance()?;
+
+        let new_cfg: Config = io(self.snapshot());
+        self.apply_io_config(new_cfg)
+    }
+
+    // Same as `run`, but the output is lent to the callback from a buffer reused every frame,
+    // which the callback may swap for a spare one to keep the frame.
+    pub fn run_with<F>(&mut self, mut io: F) -> Result<(), Error>
+    where
+        F: FnMut(&mut SimulatorOutput) -> Config,
+    {
+        self.advance()?;
+
+        let mut output: SimulatorOutput = mem::take(&mut self.output);
+        self.snapshot_into(&mut output);
+        let new_cfg: Config = io(&mut output);
+        self.output = output;
+
+        self.apply_io_config(new_cfg)
+    }
+
+    fn advance(&mut self) -> Result<(), Error> {
+        let pending_steps: usize = self
+            .world
+            .run(|mut cfg: UniqueViewMut<Config>| mem::take(&mut cfg.steps));
+
         if self.paused {
-            self.world.run(CalculateDeltaTime::system);
+            self.world
+                .run(|mut clock: UniqueViewMut<Clock>| clock.resync());
+            self.step_n(pending_steps)?;
         } else {
+            let steps: usize =
+                self.world
+                    .run(|cfg: UniqueView<Config>, mut clock: UniqueViewMut<Clock>| {
+                        let frame_time: Duration = clock.frame_time();
+                        clock.accumulate(
+                            frame_time,
+                            cfg.time_scale,
+                            DeltaTime::new(cfg.time_step),
+                            cfg.max_sub_steps,
+                        )
+                    });
+
+            self.step_n(steps)?;
+        }
+
+        Ok(())
+    }
+
+    fn apply_io_config(&mut self, mut new_cfg: Config) -> Result<(), Error> {
+        // Step requests are added up rather than diffed, so a repeated request is never lost.
+        let steps: usize = mem::take(&mut new_cfg.steps);
+        if steps > 0 {
             self.world
-                .run_workload("sim")
-                .map_err(|err: RunWorkload| Error::Run(err.to_string()))?;
+                .run(|mut cfg: UniqueViewMut<Config>| cfg.steps += steps);
+        }
+
+        if new_cfg != self.io_cfg {
+            let mut cfg: Config = self.config();
+            cfg.apply_changes(&self.io_cfg, &new_cfg);
+
+            self.set_config(cfg)?;
+            self.io_cfg = new_cfg;
         }
 
-        let mut new_cfg: Config = Config::default();
+        Ok(())
+    }
 
+    pub fn step(&mut self) -> Result<(), Error> {
+        self.step_n(1)
+    }
+
+    pub fn step_n(&mut self, ticks: usize) -> Result<(), Error> {
+        for _ in 0..ticks {
+            self.play_scenario()?;
+            self.world.run_workload("sim").map_err(Error::Run)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn snapshot(&self) -> SimulatorOutput {
+        let mut output: SimulatorOutput = SimulatorOutput::default();
+        self.snapshot_into(&mut output);
+        output
+    }
+
+    pub fn snapshot_into(&self, output: &mut SimulatorOutput) {
         self.world.run(
             |positions: View<Position>,
              velocities: View<Velocity>,
              speeds: View<Speed>,
-             densities: View<Density>| {
-                new_cfg = io(SimulatorOutput::build(
-                    positions, velocities, speeds, densities,
-                ));
+             densities: View<Density>,
+             fish: View<FishIdentifier>,
+             predators: View<PredatorIdentifier>,
+             state: (
+                View<Stress>,
+                View<Social>,
+                View<TargetVelocity>,
+                View<TargetSpeed>,
+            ),
+             chunks: UniqueView<Chunks>| {
+                output.fill(
+                    positions,
+                    velocities,
+                    speeds,
+                    densities,
+                    fish,
+                    predators,
+                    state,
+                    &self.output_fields,
+                );
+                output.chunk_size = chunks.chunk_size();
             },
         );
+    }
 
-        if self.world.run(|cfg: UniqueView<Config>| *cfg != new_cfg) {
-            self.update_config(new_cfg);
+    pub fn set_output_field(&mut self, field: OutputField, enabled: bool) {
+        if enabled {
+            self.output_fields.insert(field);
+        } else {
+            self.output_fields.remove(&field);
         }
+    }
 
-        self.paused = new_cfg.paused;
+    pub fn is_output_field_enabled(&self, field: OutputField) -> bool {
+        self.output_fields.contains(&field)
+    }
 
-        Ok(())
+    pub fn config(&self) -> Config {
+        self.world.run(|cfg: UniqueView<Config>| cfg.clone())
+    }
+
+    pub fn set_system_enabled(&mut self, system: SimulationSystem, enabled: bool) {
+        self.world
+            .run(|mut systems: UniqueViewMut<EnabledSystems>| systems.set_enabled(system, enabled));
+
+        // Rules are only decided by Swarming, so they would go stale while it is disabled.
+        if system == SimulationSystem::Swarming && !enabled {
+            self.world.run(|mut socials: ViewMut<Social>| {
+                for social in (&mut socials).iter() {
+                    social.set_rule(Rule::Idle);
+                }
+            });
+        }
+    }
+
+    pub fn is_system_enabled(&self, system: SimulationSystem) -> bool {
+        self.world
+            .run(|systems: UniqueView<EnabledSystems>| systems.is_enabled(system))
+    }
+
+    pub fn set_event_enabled(&mut self, kind: EventKind, enabled: bool) {
+        self.world
+            .run(|mut events: UniqueViewMut<Events>| events.set_enabled(kind, enabled));
+    }
+
+    pub fn is_event_enabled(&self, kind: EventKind) -> bool {
+        self.world
+            .run(|events: UniqueView<Events>| events.is_enabled(kind))
+    }
+
+    pub fn drain_events(&mut self) -> Vec<SimulationEvent> {
+        self.world
+            .run(|mut events: UniqueViewMut<Events>| events.drain())
+    }
+
+    pub fn drain_events_of(&mut self, kind: EventKind) -> Vec<SimulationEvent> {
+        self.world
+            .run(|mut events: UniqueViewMut<Events>| events.drain_kind(kind))
+    }
+
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+
+    pub fn fish_entity(&self, fish_id: usize) -> Option<EntityId> {
+        self.world.run(|fish: View<FishIdentifier>| {
+            fish.iter()
+                .with_id()
+                .find(|(_, fish)| fish.0 == fish_id)
+                .map(|(id, _)| id)
+        })
+    }
+
+    pub fn fish(&self, fish_id: usize) -> Option<FishState> {
+        let id: EntityId = self.fish_entity(fish_id)?;
+
+        self.world.run(
+            |positions: View<Position>,
+             velocities: View<Velocity>,
+             speeds: View<Speed>,
+             target_velocities: View<TargetVelocity>,
+             target_speeds: View<TargetSpeed>,
+             stress: View<Stress>,
+             densities: View<Density>,
+             social: View<Social>,
+             escapes: View<Escape>| {
+                let (pos, vel, speed, target_vel, target_speed, stress, density, social, escape) =
+                    (
+                        &positions,
+                        &velocities,
+                        &speeds,
+                        &target_velocities,
+                        &target_speeds,
+                        &stress,
+                        &densities,
+                        &social,
+                        &escapes,
+                    )
+                        .get(id)
+                        .ok()?;
+
+                Some(FishState {
+                    id: fish_id,
+                    position: pos.0,
+                    velocity: vel.0,
+                    speed: speed.0.value,
+                    target_velocity: target_vel.0,
+                    target_speed: target_speed.0.value,
+                    stress: stress.0.value,
+                    density: density.value,
+                    is_in_group: social.is_in_group,
+                    refractory: escape.refractory,
+                })
+            },
+        )
+    }
+
+    pub fn fish_in_radius(&self, center: Vec2, radius: f32) -> Vec<usize> {
+        self.refresh_chunks();
+        Self::ids(self.fish_around(center, radius))
+    }
+
+    pub fn fish_in_rect(&self, min: Vec2, max: Vec2) -> Vec<usize> {
+        self.refresh_chunks();
+        Self::ids(self.fish_in_area(min, max, |pos: Vec2| {
+            pos.x >= min.x && pos.x <= max.x && pos.y >= min.y && pos.y <= max.y
+        }))
+    }
+
+    pub fn nearest_fish(&self, point: Vec2, k: usize) -> Vec<usize> {
+        self.refresh_chunks();
+
+        let total: usize = self.world.run(|fish: View<FishIdentifier>| fish.len());
+        let mut radius: f32 = self
+            .world
+            .run(|chunks: UniqueView<Chunks>| chunks.chunk_size());
+        let mut found: Vec<(usize, Vec2)> = Vec::new();
+
+        while radius.is_finite() && found.len() < k.min(total) {
+            found = self.fish_around(point, radius);
+            radius *= 2.0;
+        }
+
+        found.sort_by(|(a_id, a_pos), (b_id, b_pos)| {
+            a_pos
+                .distance_squared(point)
+                .total_cmp(&b_pos.distance_squared(point))
+                .then(a_id.cmp(b_id))
+        });
+        found.truncate(k);
+
+        Self::ids(found)
+    }
+
+    pub fn set_fish_position(&mut self, fish_id: usize, position: Vec2) -> bool {
+        if !position.is_finite() {
+            return false;
+        }
+
+        self.with_fish(fish_id, |id: EntityId, world: &World| {
+            world.run(|mut positions: ViewMut<Position>| {
+                if let Ok(mut pos) = (&mut positions).get(id) {
+                    pos.0 = position;
+                }
+            });
+        })
+    }
+
+    pub fn set_fish_velocity(&mut self, fish_id: usize, velocity: Vec2) -> bool {
+        if !velocity.is_finite() {
+            return false;
+        }
+
+        let direction: Vec2 = velocity.normalized();
+
+        self.with_fish(fish_id, |id: EntityId, world: &World| {
+            world.run(
+                |mut velocities: ViewMut<Velocity>,
+                 mut target_velocities: ViewMut<TargetVelocity>| {
+                    if let Ok((mut vel, mut target_vel)) =
+                        (&mut velocities, &mut target_velocities).get(id)
+                    {
+                        vel.0 = direction;
+                        target_vel.0 = direction;
+                    }
+                },
+            );
+        })
+    }
+
+    pub fn set_fish_speed(&mut self, fish_id: usize, speed: f32) -> bool {
+        if !speed.is_finite() {
+            return false;
+        }
+
+        self.with_fish(fish_id, |id: EntityId, world: &World| {
+            world.run(
+                |mut speeds: ViewMut<Speed>, mut target_speeds: ViewMut<TargetSpeed>| {
+                    if let Ok((mut current, mut target)) = (&mut speeds, &mut target_speeds).get(id)
+                    {
+                        current.0 = Scalar::new(speed);
+                        target.0 = Scalar::new(speed);
+                    }
+                },
+            );
+        })
+    }
+
+    pub fn set_fish_stress(&mut self, fish_id: usize, stress: f32) -> bool {
+        if !stress.is_finite() {
+            return false;
+        }
+
+        self.with_fish(fish_id, |id: EntityId, world: &World| {
+            world.run(|mut stresses: ViewMut<Stress>| {
+                if let Ok(mut current) = (&mut stresses).get(id) {
+                    current.0 = Scalar::new(stress);
+                }
+            });
+        })
+    }
+
+    pub fn spawn_fish(
+        &mut self,
+        amount: usize,
+        pattern: &SpawnPattern,
+    ) -> Result<Range<usize>, Error> {
+        pattern.validate().map_err(ConfigError::SpawnPattern)?;
+
+        let cfg: Config = self.config();
+        let fish_ids: Range<usize> = Fish::add_pattern(&mut self.world, amount, pattern, &cfg);
+
+        self.world.run(|mut cfg: UniqueViewMut<Config>| {
+            cfg.entity_count += fish_ids.len();
+        });
+
+        Ok(fish_ids)
+    }
+
+    pub fn remove_fish(&mut self, amount: usize, policy: &RemovalPolicy) -> usize {
+        let removed: usize = Fish::remove(&mut self.world, amount, policy);
+
+        self.world.run(|mut cfg: UniqueViewMut<Config>| {
+            cfg.entity_count = cfg.entity_count.saturating_sub(removed);
+        });
+
+        removed
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.world
+            .run(|source: UniqueView<RandomSource>| source.seed())
+    }
+
+    pub fn set_scenario(&mut self, scenario: Scenario) {
+        self.scenario = Some(scenario);
+    }
+
+    pub fn scenario(&self) -> Option<&Scenario> {
+        self.scenario.as_ref()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.world.run(|clock: UniqueView<Clock>| clock.elapsed())
+    }
+
+    pub fn ticks(&self) -> u64 {
+        self.world.run(|clock: UniqueView<Clock>| clock.ticks())
+    }
+
+    fn with_fish<F>(&mut self, fish_id: usize, f: F) -> bool
+    where
+        F: FnOnce(EntityId, &World),
+    {
+        let Some(id) = self.fish_entity(fish_id) else {
+            return false;
+        };
+
+        f(id, &self.world);
+        true
+    }
+
+    fn refresh_chunks(&self) {
+        self.world.run(LoadChunks::system);
+    }
+
+    fn fish_around(&self, center: Vec2, radius: f32) -> Vec<(usize, Vec2)> {
+        let offset: Vec2 = Vec2::new(radius, radius);
+        let radius_squared: f32 = radius * radius;
+
+        self.fish_in_area(center - offset, center + offset, |pos: Vec2| {
+            pos.distance_squared(center) <= radius_squared
+        })
+    }
+
+    fn fish_in_area<F>(&self, min: Vec2, max: Vec2, filter: F) -> Vec<(usize, Vec2)>
+    where
+        F: Fn(Vec2) -> bool,
+    {
+        self.world.run(
+            |fish: View<FishIdentifier>, positions: View<Position>, chunks: UniqueView<Chunks>| {
+                match chunks.load_area(&min, &max) {
+                    Some(area) => area
+                        .flatten()
+                        .filter_map(|&id| (&fish, &positions).get(id).ok())
+                        .map(|(fish, pos)| (fish.0, pos.0))
+                        .filter(|&(_, pos)| filter(pos))
+                        .collect(),
+                    None => (&fish, &positions)
+                        .iter()
+                        .map(|(fish, pos)| (fish.0, pos.0))
+                        .filter(|&(_, pos)| filter(pos))
+                        .collect(),
+                }
+            },
+        )
+    }
+
+    fn ids(fish: Vec<(usize, Vec2)>) -> Vec<usize> {
+        fish.into_iter().map(|(id, _)| id).collect()
+    }
+
+    fn play_scenario(&mut self) -> Result<(), Error> {
+        let Some(scenario) = &mut self.scenario else {
+            return Ok(());
+        };
+
+        let elapsed: Duration = self.world.run(|clock: UniqueView<Clock>| clock.elapsed());
+        let mut due: Vec<(usize, ScenarioEvent)> = Vec::new();
+        while let Some(event) = scenario.next_due(elapsed) {
+            due.push(event);
+        }
+
+        // Each action is applied on its own, so an invalid one does not drop the others.
+        let mut failed: Option<Error> = None;
+        for (index, event) in due {
+            let mut cfg: Config = self.config();
+            event.action.apply(&mut cfg);
+
+            match self.set_config(cfg) {
+                Err(Error::Config(source)) => {
+                    failed.get_or_insert(Error::ScenarioEvent { index, source });
+                }
+                result => result?,
+            }
+        }
+
+        failed.map_or(Ok(()), Err)
+    }
+
+    #[deprecated(note = "use `set_config` instead")]
+    pub fn update_config(&mut self, new_cfg: Config) -> Result<(), Error> {
+        self.set_config(new_cfg)
     }
 
-    pub fn update_config(&mut self, new_cfg: Config) {
+    pub fn set_config(&mut self, new_cfg: Config) -> Result<(), Error> {
+        new_cfg.validate()?;
+        self.paused = new_cfg.paused;
+
         let old_cfg: Config = self
             .world
-            .run(|mut cfg: UniqueViewMut<Config>| mem::replace(&mut *cfg, new_cfg));
+            .run(|mut cfg: UniqueViewMut<Config>| mem::replace(&mut *cfg, new_cfg.clone()));
+
+        let moved: bool = old_cfg.origin != new_cfg.origin;
+        let resized: bool = (old_cfg.width, old_cfg.height) != (new_cfg.width, new_cfg.height);
+        if moved || (resized && new_cfg.arena.is_bounded()) {
+            self.resize(&old_cfg, &new_cfg);
+        }
 
         match new_cfg.entity_count.cmp(&old_cfg.entity_count) {
             Ordering::Greater => {
                 let to_add: usize = new_cfg.entity_count - old_cfg.entity_count;
-                Fish::add(&mut self.world, to_add, new_cfg);
+                Fish::add(&mut self.world, to_add, &new_cfg);
             }
             Ordering::Less => {
                 let to_remove: usize = old_cfg.entity_count - new_cfg.entity_count;
-                Fish::remove(&mut self.world, to_remove);
+                let removed: usize =
+                    Fish::remove(&mut self.world, to_remove, &new_cfg.removal_policy);
+                if removed < to_remove {
+                    Fish::remove(&mut self.world, to_remove - removed, &RemovalPolicy::Random);
+                }
+            }
+            _ => (),
+        }
+
+        match new_cfg.predator_count.cmp(&old_cfg.predator_count) {
+            Ordering::Greater => {
+                let to_add: usize = new_cfg.predator_count - old_cfg.predator_count;
+                Predator::add(&mut self.world, to_add, &new_cfg);
+            }
+            Ordering::Less => {
+                let to_remove: usize = old_cfg.predator_count - new_cfg.predator_count;
+                Predator::remove(&mut self.world, to_remove);
             }
             _ => (),
         }
+
+        if moved || resized {
+            self.refresh_chunks();
+        }
+
+        Ok(())
+    }
+
+    // Fish follow a moved area, and are relocated by the resize policy when a bounded one is resized.
+    fn resize(&mut self, old_cfg: &Config, new_cfg: &Config) {
+        let old_origin: Vec2 = old_cfg.origin.into();
+        let new_origin: Vec2 = new_cfg.origin.into();
+        let from: Vec2 = Vec2::new(old_cfg.width as f32, old_cfg.height as f32);
+        let to: Vec2 = Vec2::new(new_cfg.width as f32, new_cfg.height as f32);
+        let relocate: bool = from != to && new_cfg.arena.is_bounded();
+
+        self.world.run(
+            |mut positions: ViewMut<Position>, mut source: UniqueViewMut<RandomSource>| {
+                let mut rng: StdRng = source.fork();
+                for pos in (&mut positions).iter() {
+                    let mut local: Vec2 = pos.0 - old_origin;
+                    if relocate {
+                        local = new_cfg.resize_policy.relocate(
+                            &mut rng,
+                            &new_cfg.arena,
+                            local,
+                            from,
+                            to,
+                        );
+                    }
+                    pos.0 = new_origin + local;
+                }
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Arena, ResizePolicy};
+    use shipyard::{Component, Unique};
+
+    fn tick(simulator: &mut FishShoalSimulator, count: usize) -> SimulatorOutput {
+        simulator.step_n(count).unwrap();
+        simulator.snapshot()
+    }
+
+    #[test]
+    fn simulator_seed() {
+        let mut a: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+        let mut b: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+        let mut c: FishShoalSimulator = FishShoalSimulator::with_seed(43).unwrap();
+
+        assert_eq!(a.seed(), 42);
+
+        let a_output: SimulatorOutput = tick(&mut a, 50);
+        let b_output: SimulatorOutput = tick(&mut b, 50);
+        let c_output: SimulatorOutput = tick(&mut c, 50);
+
+        assert_eq!(a_output.positions, b_output.positions);
+        assert_eq!(a_output.velocities, b_output.velocities);
+        assert_eq!(a_output.speeds, b_output.speeds);
+        assert_ne!(a_output.positions, c_output.positions);
+    }
+
+    #[test]
+    fn simulator_step() {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+        let mut cfg: Config = Config {
+            paused: true,
+            ..Config::default()
+        };
+
+        let mut io_cfg: Config = cfg.clone();
+        simulator.run(move |_| io_cfg.clone()).unwrap();
+        let ticks: u64 = simulator.ticks();
+
+        simulator.step().unwrap();
+        simulator.step_n(3).unwrap();
+        assert_eq!(simulator.ticks(), ticks + 4);
+
+        cfg.steps = 2;
+        io_cfg = cfg.clone();
+        simulator.run(move |_| io_cfg.clone()).unwrap();
+
+        cfg.steps = 1;
+        for _ in 0..2 {
+            simulator.run(|_| cfg.clone()).unwrap();
+        }
+        simulator.run(|_| Config::default()).unwrap();
+        assert_eq!(simulator.ticks(), ticks + 8);
+    }
+
+    #[test]
+    fn simulator_open_water() {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+        let before: SimulatorOutput = simulator.snapshot();
+        simulator
+            .set_config(Config {
+                origin: [-50_000.0, -50_000.0],
+                arena: Arena::Open,
+                entity_count: 600,
+                predator_count: 2,
+                ..Config::default()
+            })
+            .unwrap();
+
+        for (&id, &[x, y]) in before.ids.iter().zip(&before.positions) {
+            let state: FishState = simulator.fish(id).unwrap();
+            assert_eq!(state.position, Vec2::new(x - 50_000.0, y - 50_000.0));
+        }
+
+        let output: SimulatorOutput = tick(&mut simulator, 20);
+        let far_away: usize = output
+            .positions
+            .iter()
+            .filter(|&&[x, y]| x < -40_000.0 && y < -40_000.0)
+            .count();
+
+        assert_eq!(far_away, 600);
+    }
+
+    #[test]
+    fn simulator_persistent_ids() {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+        let cfg: Config = Config::default();
+
+        simulator
+            .set_config(Config {
+                entity_count: 400,
+                ..cfg.clone()
+            })
+            .unwrap();
+        let remaining: Vec<usize> = tick(&mut simulator, 1).ids;
+
+        simulator.set_config(cfg.clone()).unwrap();
+        let mut ids: Vec<usize> = tick(&mut simulator, 1).ids;
+        ids.sort_unstable();
+        ids.dedup();
+
+        assert_eq!(ids.len(), cfg.entity_count);
+        assert!(
+            ids.iter()
+                .filter(|id| !remaining.contains(id))
+                .all(|&id| id >= cfg.entity_count)
+        );
+    }
+
+    #[test]
+    fn simulator_removal_policies() {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+        let ids: Vec<usize> = tick(&mut simulator, 1).ids;
+
+        assert_eq!(simulator.remove_fish(10, &RemovalPolicy::Oldest), 10);
+        assert_eq!(simulator.remove_fish(10, &RemovalPolicy::Newest), 10);
+        assert_eq!(
+            simulator.remove_fish(usize::MAX, &RemovalPolicy::Ids(vec![10, 20, 10_000])),
+            2
+        );
+
+        let output: SimulatorOutput = tick(&mut simulator, 1);
+        let remaining: Vec<usize> = ids
+            .iter()
+            .copied()
+            .filter(|id| output.ids.contains(id))
+            .collect();
+
+        assert_eq!(output.ids.len(), 478);
+        assert_eq!(remaining.first(), Some(&11));
+        assert_eq!(remaining.last(), Some(&489));
+        assert!(!remaining.contains(&20));
+
+        let (min, max): (Vec2, Vec2) = (Vec2::new(0.0, 0.0), Vec2::new(500.0, 500.0));
+        simulator.remove_fish(usize::MAX, &RemovalPolicy::Region { min, max });
+        let output: SimulatorOutput = tick(&mut simulator, 0);
+
+        assert!(
+            output
+                .positions
+                .iter()
+                .all(|&[x, y]| x < min.x || x > max.x || y < min.y || y > max.y)
+        );
+        assert_eq!(simulator.config().entity_count, output.ids.len());
+    }
+
+    #[test]
+    fn simulator_most_isolated_removal() {
+        let center: Vec2 = Vec2::new(960.0, 540.0);
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::builder()
+            .with_config(Config {
+                entity_count: 100,
+                spawn_pattern: SpawnPattern::Ball {
+                    center,
+                    radius: 200.0,
+                },
+                ..Config::default()
+            })
+            .with_seed(42)
+            .build()
+            .unwrap();
+        tick(&mut simulator, 1);
+
+        let spawned: Range<usize> = simulator
+            .spawn_fish(
+                20,
+                &SpawnPattern::Ball {
+                    center,
+                    radius: 3.0,
+                },
+            )
+            .unwrap();
+        assert_eq!(simulator.remove_fish(20, &RemovalPolicy::MostIsolated), 20);
+
+        assert!(spawned.into_iter().all(|id| simulator.fish(id).is_some()));
+    }
+
+    #[test]
+    fn simulator_scenario_invalid_event() {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+        simulator.set_scenario("0 set avoidance_radius 40\n0 spawn 10".parse().unwrap());
+
+        assert!(matches!(
+            simulator.step(),
+            Err(Error::ScenarioEvent {
+                index: 0,
+                source: ConfigError::RadiusOrder { .. },
+            })
+        ));
+        assert_eq!(simulator.config().entity_count, 510);
+        assert_eq!(simulator.config().avoidance_radius, 10.0);
+        assert!(simulator.step().is_ok());
+    }
+
+    #[test]
+    fn simulator_builder() {
+        let fish: [(Vec2, Vec2); 2] = [
+            (Vec2::new(10.0, 20.0), Vec2::new(1.0, 0.0)),
+            (Vec2::new(30.0, 40.0), Vec2::new(0.0, 2.0)),
+        ];
+
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::builder()
+            .with_config(Config {
+                entity_count: 42,
+                predator_count: 2,
+                ..Config::default()
+            })
+            .with_seed(7)
+            .with_school(fish)
+            .build()
+            .unwrap();
+
+        let output: SimulatorOutput = tick(&mut simulator, 0);
+
+        assert_eq!(simulator.seed(), 7);
+        assert_eq!(output.ids.len(), 42);
+        assert_eq!(output.predator_positions.len(), 2);
+        assert_eq!(&output.positions[..2], &[[10.0, 20.0], [30.0, 40.0]]);
+        assert_eq!(&output.velocities[..2], &[[1.0, 0.0], [0.0, 1.0]]);
+
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::builder()
+            .with_config(Config {
+                origin: [-500.0, 200.0],
+                entity_count: 42,
+                ..Config::default()
+            })
+            .with_seed(7)
+            .with_school(fish)
+            .build()
+            .unwrap();
+
+        let cfg: Config = simulator.config();
+        let output: SimulatorOutput = tick(&mut simulator, 0);
+        assert_eq!(&output.positions[..2], &[[-490.0, 220.0], [-470.0, 240.0]]);
+        assert!(output.positions.iter().all(|&[x, y]| {
+            (-500.0..-500.0 + cfg.width as f32).contains(&x)
+                && (200.0..200.0 + cfg.height as f32).contains(&y)
+        }));
+    }
+
+    #[test]
+    fn simulator_builder_validation() {
+        let result: Result<FishShoalSimulator, Error> = FishShoalSimulator::builder()
+            .with_config(Config {
+                height: 0,
+                ..Config::default()
+            })
+            .build();
+        assert!(matches!(result, Err(Error::Config(_))));
+
+        let result: Result<FishShoalSimulator, Error> = FishShoalSimulator::builder()
+            .with_fish(Vec2::new(f32::NAN, 0.0), Vec2::ZERO)
+            .build();
+        assert!(matches!(result, Err(Error::InvalidFish { index: 0 })));
+    }
+
+    #[test]
+    fn simulator_invalid_config() {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+        let before: Config = simulator.config();
+
+        let result: Result<(), Error> = simulator.set_config(Config {
+            entity_count: 10,
+            direction_change_prob: 2.0,
+            ..before.clone()
+        });
+        assert!(matches!(
+            result,
+            Err(Error::Config(ConfigError::Probability {
+                field: "direction_change_prob",
+                ..
+            }))
+        ));
+        assert_eq!(simulator.config(), before);
+        assert_eq!(simulator.snapshot().ids.len(), before.entity_count);
+
+        let invalid: Config = Config {
+            avoidance_radius: 40.0,
+            ..Config::default()
+        };
+        for _ in 0..2 {
+            let result: Result<(), Error> = simulator.run(|_| invalid.clone());
+            assert!(matches!(
+                result,
+                Err(Error::Config(ConfigError::RadiusOrder { .. }))
+            ));
+            assert_eq!(simulator.config(), before);
+        }
+
+        simulator.run(|_| Config::default()).unwrap();
+        assert_eq!(simulator.config(), before);
+    }
+
+    #[test]
+    fn simulator_resize() {
+        for policy in ResizePolicy::ALL {
+            let mut simulator: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+            let cfg: Config = Config {
+                resize_policy: policy,
+                ..simulator.config()
+            };
+            simulator.set_config(cfg.clone()).unwrap();
+            let before: SimulatorOutput = tick(&mut simulator, 5);
+
+            simulator
+                .set_config(Config {
+                    width: cfg.width / 2,
+                    height: cfg.height / 4,
+                    ..cfg.clone()
+                })
+                .unwrap();
+
+            let after: SimulatorOutput = simulator.snapshot();
+            let (width, height): (f32, f32) = ((cfg.width / 2) as f32, (cfg.height / 4) as f32);
+            assert_eq!(after.ids, before.ids);
+            let inside: Vec<usize> = simulator.fish_in_rect(Vec2::ZERO, Vec2::new(width, height));
+            let arena: Arena = Arena::Rectangle;
+            assert!(
+                after
+                    .positions
+                    .iter()
+                    .all(|&pos| arena.contains(pos.into(), width, height))
+            );
+            assert_eq!(inside.len(), after.ids.len(), "{}", policy.name());
+        }
+
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+        let cfg: Config = simulator.config();
+        simulator
+            .set_config(Config {
+                width: cfg.width * 2,
+                ..cfg.clone()
+            })
+            .unwrap();
+        let grown: usize = simulator
+            .fish_in_rect(
+                Vec2::new(cfg.width as f32, 0.0),
+                Vec2::new(cfg.width as f32 * 2.0, cfg.height as f32),
+            )
+            .len();
+        assert!(grown > cfg.entity_count / 4);
+    }
+
+    #[test]
+    fn simulator_output_buffer() {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+        simulator.step_n(5).unwrap();
+
+        let mut output: SimulatorOutput = SimulatorOutput::default();
+        simulator.snapshot_into(&mut output);
+        assert_eq!(output.ids, simulator.snapshot().ids);
+
+        let buffer: *const [f32; 2] = output.positions.as_ptr();
+        simulator.set_output_field(OutputField::Velocities, false);
+        simulator.set_output_field(OutputField::Predators, false);
+        assert!(!simulator.is_output_field_enabled(OutputField::Velocities));
+
+        simulator.snapshot_into(&mut output);
+        assert_eq!(output.positions.as_ptr(), buffer);
+        assert_eq!(output.positions.len(), output.ids.len());
+        assert!(output.velocities.is_empty());
+        assert!(output.predator_positions.is_empty());
+
+        let cfg: Config = simulator.config();
+        let mut counts: Vec<usize> = Vec::new();
+        for _ in 0..3 {
+            simulator
+                .run_with(|output: &mut SimulatorOutput| {
+                    counts.push(output.positions.len());
+                    cfg.clone()
+                })
+                .unwrap();
+        }
+        assert_eq!(counts, vec![cfg.entity_count; 3]);
+
+        let mut spare: SimulatorOutput = SimulatorOutput::default();
+        spare.positions.reserve(cfg.entity_count);
+        let buffer: *const [f32; 2] = spare.positions.as_ptr();
+        simulator
+            .run_with(|output: &mut SimulatorOutput| {
+                mem::swap(output, &mut spare);
+                cfg.clone()
+            })
+            .unwrap();
+        simulator
+            .run_with(|output: &mut SimulatorOutput| {
+                assert_eq!(output.positions.as_ptr(), buffer);
+                cfg.clone()
+            })
+            .unwrap();
+        assert_eq!(spare.positions.len(), cfg.entity_count);
+    }
+
+    #[test]
+    fn simulator_output_state() {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+        let output: SimulatorOutput = tick(&mut simulator, 30);
+        assert!(output.stress.is_empty() && output.rules.is_empty());
+
+        for field in OutputField::ALL {
+            simulator.set_output_field(field, true);
+        }
+        let output: SimulatorOutput = tick(&mut simulator, 1);
+
+        let count: usize = output.ids.len();
+        assert_eq!(output.stress.len(), count);
+        assert_eq!(output.is_in_group.len(), count);
+        assert_eq!(output.target_velocities.len(), count);
+        assert_eq!(output.target_speeds.len(), count);
+        assert_eq!(output.rules.len(), count);
+
+        let state: FishState = simulator.fish(output.ids[0]).unwrap();
+        assert_eq!(output.stress[0], state.stress);
+        assert_eq!(output.is_in_group[0], state.is_in_group);
+        assert_eq!(
+            output.target_velocities[0],
+            <[f32; 2]>::from(state.target_velocity)
+        );
+        assert!(output.rules.iter().any(|&rule| rule != Rule::Idle));
+        assert!(
+            output
+                .is_in_group
+                .iter()
+                .zip(&output.rules)
+                .all(|(&grouped, &rule)| grouped || rule == Rule::Idle || rule == Rule::Escape)
+        );
+
+        let id: EntityId = simulator.fish_entity(output.ids[0]).unwrap();
+        simulator.world_mut().remove::<Stress>(id);
+        simulator.set_system_enabled(SimulationSystem::Swarming, false);
+        let output: SimulatorOutput = tick(&mut simulator, 1);
+
+        assert_eq!(output.stress.len(), output.ids.len());
+        assert_eq!(output.rules.len(), output.ids.len());
+        assert!(output.rules.iter().all(|&rule| rule == Rule::Idle));
+    }
+
+    #[test]
+    fn simulator_chunk_size_independent() {
+        let run = |chunk_size: f32| -> SimulatorOutput {
+            let mut simulator: FishShoalSimulator = FishShoalSimulator::builder()
+                .with_config(Config {
+                    entity_count: 300,
+                    width: 400,
+                    height: 400,
+                    chunk_size: Some(chunk_size),
+                    ..Config::default()
+                })
+                .with_seed(42)
+                .build()
+                .unwrap();
+            tick(&mut simulator, 60)
+        };
+
+        let small: SimulatorOutput = run(12.5);
+        let large: SimulatorOutput = run(50.0);
+        let tiny: SimulatorOutput = run(1e-6);
+
+        assert!(small.densities.iter().any(|&density| density > 0));
+        assert_eq!(small.densities, large.densities);
+        assert_eq!(small.positions, large.positions);
+        assert_eq!(small.velocities, large.velocities);
+        assert_eq!(tiny.chunk_size, 12.5);
+        assert_eq!(tiny.positions, large.positions);
+    }
+
+    #[test]
+    fn simulator_builder_systems() {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::builder()
+            .with_seed(42)
+            .with_system_enabled(SimulationSystem::Swarming, false)
+            .build()
+            .unwrap();
+        let mut reference: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+
+        assert!(tick(&mut simulator, 30).densities.iter().all(|&d| d == 0));
+        assert!(tick(&mut reference, 30).densities.iter().any(|&d| d > 0));
+    }
+
+    #[test]
+    fn simulator_snapshot_config() {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+        let cfg: Config = Config {
+            entity_count: 100,
+            predator_count: 1,
+            paused: true,
+            ..Config::default()
+        };
+
+        simulator.set_config(cfg.clone()).unwrap();
+        assert_eq!(simulator.config(), cfg);
+
+        let before: SimulatorOutput = simulator.snapshot();
+        assert_eq!(before.ids.len(), 100);
+        assert_eq!(before.predator_positions.len(), 1);
+
+        simulator.run(|_| cfg.clone()).unwrap();
+        assert_eq!(simulator.snapshot().positions, before.positions);
+
+        simulator.step_n(5).unwrap();
+        assert_ne!(simulator.snapshot().positions, before.positions);
+
+        #[allow(deprecated)]
+        simulator
+            .update_config(Config {
+                entity_count: 50,
+                ..cfg
+            })
+            .unwrap();
+        assert_eq!(simulator.snapshot().ids.len(), 50);
+    }
+
+    #[test]
+    fn simulator_queries() {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::builder()
+            .with_config(Config {
+                entity_count: 4,
+                ..Config::default()
+            })
+            .with_school([
+                (Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0)),
+                (Vec2::new(10.0, 0.0), Vec2::new(1.0, 0.0)),
+                (Vec2::new(20.0, 0.0), Vec2::new(1.0, 0.0)),
+                (Vec2::new(500.0, 500.0), Vec2::new(1.0, 0.0)),
+            ])
+            .build()
+            .unwrap();
+
+        let sorted = |mut ids: Vec<usize>| {
+            ids.sort_unstable();
+            ids
+        };
+
+        assert_eq!(
+            sorted(simulator.fish_in_radius(Vec2::ZERO, 15.0)),
+            vec![0, 1]
+        );
+        assert_eq!(
+            sorted(simulator.fish_in_rect(Vec2::new(5.0, -1.0), Vec2::new(25.0, 1.0))),
+            vec![1, 2]
+        );
+        assert_eq!(simulator.nearest_fish(Vec2::new(19.0, 0.0), 2), vec![2, 1]);
+        assert_eq!(simulator.nearest_fish(Vec2::ZERO, 10), vec![0, 1, 2, 3]);
+        assert_eq!(
+            simulator.fish(3).map(|fish: FishState| fish.position),
+            Some(Vec2::new(500.0, 500.0))
+        );
+        assert_eq!(simulator.fish(4), None);
+
+        assert!(simulator.set_fish_position(3, Vec2::new(1.0, 1.0)));
+        assert!(simulator.set_fish_velocity(3, Vec2::new(0.0, 2.0)));
+        assert!(simulator.set_fish_speed(3, 30.0));
+        assert!(simulator.set_fish_stress(3, 0.5));
+        assert!(!simulator.set_fish_speed(4, 30.0));
+        assert!(!simulator.set_fish_position(3, Vec2::new(f32::NAN, 1.0)));
+        assert!(!simulator.set_fish_velocity(3, Vec2::new(f32::INFINITY, 0.0)));
+        assert!(!simulator.set_fish_speed(3, f32::NAN));
+        assert!(!simulator.set_fish_stress(3, f32::NEG_INFINITY));
+
+        let fish: FishState = simulator.fish(3).unwrap();
+        assert_eq!(fish.position, Vec2::new(1.0, 1.0));
+        assert_eq!(fish.velocity, Vec2::new(0.0, 1.0));
+        assert_eq!(fish.speed, 30.0);
+        assert_eq!(fish.stress, 0.5);
+        assert_eq!(
+            sorted(simulator.fish_in_radius(Vec2::ZERO, 2.0)),
+            vec![0, 3]
+        );
+    }
+
+    #[test]
+    fn simulator_spawn_patterns() {
+        let center: Vec2 = Vec2::new(300.0, 300.0);
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::builder()
+            .with_config(Config {
+                entity_count: 50,
+                spawn_pattern: SpawnPattern::Ball {
+                    center,
+                    radius: 25.0,
+                },
+                ..Config::default()
+            })
+            .with_seed(42)
+            .build()
+            .unwrap();
+
+        assert_eq!(simulator.fish_in_radius(center, 25.0).len(), 50);
+
+        let point: Vec2 = Vec2::new(800.0, 100.0);
+        let spawned: Range<usize> = simulator
+            .spawn_fish(
+                10,
+                &SpawnPattern::Point {
+                    position: point,
+                    heading: Vec2::new(1.0, 0.0),
+                    spread: 0.0,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(spawned, 50..60);
+        assert_eq!(simulator.config().entity_count, 60);
+        assert!(matches!(
+            simulator.spawn_fish(
+                5,
+                &SpawnPattern::Clusters {
+                    count: 0,
+                    radius: 1.0,
+                },
+            ),
+            Err(Error::Config(ConfigError::SpawnPattern(_)))
+        ));
+        assert_eq!(simulator.config().entity_count, 60);
+        let fish: FishState = simulator.fish(55).unwrap();
+        assert_eq!(fish.position, point);
+        assert_eq!(fish.velocity, Vec2::new(1.0, 0.0));
+
+        simulator
+            .set_config(Config {
+                entity_count: 70,
+                ..simulator.config()
+            })
+            .unwrap();
+        assert_eq!(simulator.fish_in_radius(center, 25.0).len(), 60);
+    }
+
+    #[derive(Component)]
+    struct Pinned(Vec2);
+
+    #[derive(Unique, Default)]
+    struct Ticks(usize);
+
+    fn pin(mut positions: ViewMut<Position>, pinned: View<Pinned>) {
+        for (pos, pinned) in (&mut positions, &pinned).iter() {
+            pos.0 = pinned.0;
+        }
+    }
+
+    fn count(mut ticks: UniqueViewMut<Ticks>) {
+        ticks.0 += 1;
+    }
+
+    #[test]
+    fn simulator_custom_systems() {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::builder()
+            .with_seed(42)
+            .with_system_after(Stage::Boundary, pin)
+            .with_system_before(Stage::Motion, count)
+            .build()
+            .unwrap();
+
+        let anchor: Vec2 = Vec2::new(100.0, 100.0);
+        let id: EntityId = simulator.fish_entity(3).unwrap();
+        simulator.world_mut().add_unique(Ticks::default());
+        simulator.world_mut().add_component(id, Pinned(anchor));
+
+        simulator.step_n(10).unwrap();
+
+        assert_eq!(simulator.fish(3).unwrap().position, anchor);
+        assert_ne!(simulator.fish(4).unwrap().position, anchor);
+        assert_eq!(
+            simulator.world().run(|ticks: UniqueView<Ticks>| ticks.0),
+            10
+        );
+    }
+
+    #[test]
+    fn simulator_toggle_systems() {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+
+        simulator.set_system_enabled(SimulationSystem::Swarming, false);
+        assert!(!simulator.is_system_enabled(SimulationSystem::Swarming));
+        assert!(tick(&mut simulator, 10).densities.iter().all(|&d| d == 0));
+
+        simulator.set_system_enabled(SimulationSystem::Swarming, true);
+        assert!(tick(&mut simulator, 10).densities.iter().any(|&d| d > 0));
+    }
+
+    #[test]
+    fn simulator_events() {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::with_seed(42).unwrap();
+        simulator.step_n(20).unwrap();
+        assert!(simulator.drain_events().is_empty());
+
+        let mut builder: SimulatorBuilder = FishShoalSimulator::builder().with_seed(42);
+        for kind in EventKind::ALL {
+            builder = builder.with_event(kind);
+        }
+        let mut simulator: FishShoalSimulator = builder.build().unwrap();
+        simulator.step_n(200).unwrap();
+
+        let joined: Vec<SimulationEvent> = simulator.drain_events_of(EventKind::JoinedGroup);
+        assert!(!joined.is_empty());
+        assert!(
+            joined
+                .iter()
+                .all(|event| event.kind() == EventKind::JoinedGroup)
+        );
+
+        let events: Vec<SimulationEvent> = simulator.drain_events();
+        for kind in [
+            EventKind::EnteredAvoidance,
+            EventKind::LeftGroup,
+            EventKind::CrossedBoundary,
+            EventKind::ClusterSplit,
+        ] {
+            assert!(events.iter().any(|event| event.kind() == kind), "{kind:?}");
+        }
+        assert!(events.iter().all(|event| (1..=200).contains(&event.tick())));
+        assert!(simulator.drain_events().is_empty());
+
+        let fish_id: usize = simulator.snapshot().ids[0];
+        assert!(simulator.set_fish_position(fish_id, Vec2::new(0.0, 500.0)));
+        assert!(simulator.set_fish_speed(fish_id, 0.0));
+        simulator.step().unwrap();
+
+        let crossed: Vec<SimulationEvent> = simulator.drain_events_of(EventKind::CrossedBoundary);
+        assert!(crossed.contains(&SimulationEvent::CrossedBoundary {
+            tick: 201,
+            fish: fish_id,
+        }));
+        assert_eq!(
+            simulator.fish(fish_id).unwrap().position,
+            Vec2::new(1_919.0, 500.0)
+        );
+    }
+
+    #[test]
+    fn simulator_capture_event() {
+        let mut simulator: FishShoalSimulator = FishShoalSimulator::builder()
+            .with_config(Config {
+                entity_count: 0,
+                predator_count: 1,
+                ..Config::default()
+            })
+            .with_event(EventKind::Captured)
+            .build()
+            .unwrap();
+
+        let [x, y]: [f32; 2] = simulator.snapshot().predator_positions[0];
+        simulator
+            .spawn_fish(
+                1,
+                &SpawnPattern::Point {
+                    position: Vec2::new(x, y),
+                    heading: Vec2::new(1.0, 0.0),
+                    spread: 0.0,
+                },
+            )
+            .unwrap();
+        simulator.step().unwrap();
+
+        assert_eq!(
+            simulator.drain_events(),
+            vec![SimulationEvent::Captured { tick: 1, fish: 0 }]
+        );
     }
 }
@@ -0,0 +1,121 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    Config, EnabledSystems, Error, EventKind, Events, FishShoalSimulator, Scenario,
+    SimulationSystem, Stage, Vec2, custom_systems::CustomSystems,
+};
+use shipyard::IntoWorkloadSystem;
+
+#[derive(Debug, Default)]
+pub struct SimulatorBuilder {
+    config: Config,
+    seed: Option<u64>,
+    fish: Vec<(Vec2, Vec2)>,
+    systems: EnabledSystems,
+    events: Events,
+    custom: CustomSystems,
+    scenario: Option<Scenario>,
+}
+
+impl SimulatorBuilder {
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn with_fish(mut self, position: Vec2, direction: Vec2) -> Self {
+        self.fish.push((position, direction));
+        self
+    }
+
+    pub fn with_school<I>(mut self, fish: I) -> Self
+    where
+        I: IntoIterator<Item = (Vec2, Vec2)>,
+    {
+        self.fish.extend(fish);
+        self
+    }
+
+    pub fn with_system_enabled(mut self, system: SimulationSystem, enabled: bool) -> Self {
+        self.systems.set_enabled(system, enabled);
+        self
+    }
+
+    pub fn with_event(mut self, kind: EventKind) -> Self {
+        self.events.set_enabled(kind, true);
+        self
+    }
+
+    pub fn with_system_before<B, R, S>(mut self, stage: Stage, system: S) -> Self
+    where
+        S: IntoWorkloadSystem<B, R>,
+    {
+        self.custom.add_before(stage, system);
+        self
+    }
+
+    pub fn with_system_after<B, R, S>(mut self, stage: Stage, system: S) -> Self
+    where
+        S: IntoWorkloadSystem<B, R>,
+    {
+        self.custom.add_after(stage, system);
+        self
+    }
+
+    pub fn with_scenario(mut self, scenario: Scenario) -> Self {
+        self.scenario = Some(scenario);
+        self
+    }
+
+    pub fn build(self) -> Result<FishShoalSimulator, Error> {
+        let Self {
+            mut config,
+            seed,
+            fish,
+            systems,
+            events,
+            custom,
+            scenario,
+        } = self;
+
+        config.validate()?;
+
+        if let Some(index) = fish
+            .iter()
+            .position(|(pos, dir)| !(pos.is_finite() && dir.is_finite()))
+        {
+            return Err(Error::InvalidFish { index });
+        }
+
+        config.entity_count = config.entity_count.max(fish.len());
+
+        FishShoalSimulator::create(
+            config,
+            seed.unwrap_or_else(rand::random),
+            &fish,
+            systems,
+            events,
+            custom,
+            scenario,
+        )
+    }
+}
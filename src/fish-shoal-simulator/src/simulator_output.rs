@@ -14,48 +14,156 @@
  * limitations under the License.
  */
 
-use crate::{Density, Position, Speed, Velocity};
-use shipyard::{IntoIter, View};
+use crate::{
+    Density, FishIdentifier, OutputField, Position, PredatorIdentifier, Rule, Social, Speed,
+    Stress, TargetSpeed, TargetVelocity, Velocity,
+};
+use shipyard::{Get, IntoIter, View};
+use std::collections::HashSet;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct SimulatorOutput {
     pub ids: Vec<usize>,
     pub positions: Vec<[f32; 2]>,
     pub velocities: Vec<[f32; 2]>,
     pub speeds: Vec<f32>,
     pub densities: Vec<usize>,
+    pub predator_positions: Vec<[f32; 2]>,
+    pub predator_velocities: Vec<[f32; 2]>,
+    pub stress: Vec<f32>,
+    pub is_in_group: Vec<bool>,
+    pub target_velocities: Vec<[f32; 2]>,
+    pub target_speeds: Vec<f32>,
+    pub rules: Vec<Rule>,
+    pub chunk_size: f32,
 }
 
 impl SimulatorOutput {
-    pub(crate) fn build(
+    pub fn clear(&mut self) {
+        self.ids.clear();
+        self.positions.clear();
+        self.velocities.clear();
+        self.speeds.clear();
+        self.densities.clear();
+        self.predator_positions.clear();
+        self.predator_velocities.clear();
+        self.stress.clear();
+        self.is_in_group.clear();
+        self.target_velocities.clear();
+        self.target_speeds.clear();
+        self.rules.clear();
+    }
+
+    // Fills the buffers in place, keeping their capacity from one tick to the next.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn fill(
+        &mut self,
         position_view: View<Position>,
         velocity_view: View<Velocity>,
         speed_view: View<Speed>,
         density_view: View<Density>,
-    ) -> Self {
-        let mut ids: Vec<usize> = Vec::new();
-        let mut positions: Vec<[f32; 2]> = Vec::new();
-        let mut velocities: Vec<[f32; 2]> = Vec::new();
-        let mut speeds: Vec<f32> = Vec::new();
-        let mut densities: Vec<usize> = Vec::new();
+        fish_view: View<FishIdentifier>,
+        predator_view: View<PredatorIdentifier>,
+        (stress_view, social_view, target_velocity_view, target_speed_view): (
+            View<Stress>,
+            View<Social>,
+            View<TargetVelocity>,
+            View<TargetSpeed>,
+        ),
+        fields: &HashSet<OutputField>,
+    ) {
+        self.clear();
 
-        (&position_view, &velocity_view, &speed_view, &density_view)
-            .iter()
-            .with_id()
-            .for_each(|(id, (pos, vel, speed, density))| {
-                ids.push(id.uindex());
-                positions.push(pos.0.into());
-                velocities.push(vel.0.into());
-                speeds.push(speed.0.into());
-                densities.push(density.value);
-            });
+        let ids: bool = fields.contains(&OutputField::Ids);
+        let positions: bool = fields.contains(&OutputField::Positions);
+        let velocities: bool = fields.contains(&OutputField::Velocities);
+        let speeds: bool = fields.contains(&OutputField::Speeds);
+        let densities: bool = fields.contains(&OutputField::Densities);
+        let stress: bool = fields.contains(&OutputField::Stress);
+        let is_in_group: bool = fields.contains(&OutputField::IsInGroup);
+        let target_velocities: bool = fields.contains(&OutputField::TargetVelocities);
+        let target_speeds: bool = fields.contains(&OutputField::TargetSpeeds);
+        let rules: bool = fields.contains(&OutputField::Rules);
+        let state: bool = stress || is_in_group || target_velocities || target_speeds || rules;
+
+        if ids || positions || velocities || speeds || densities || state {
+            (
+                &position_view,
+                &velocity_view,
+                &speed_view,
+                &density_view,
+                &fish_view,
+            )
+                .iter()
+                .with_id()
+                .for_each(|(id, (pos, vel, speed, density, fish))| {
+                    if ids {
+                        self.ids.push(fish.0);
+                    }
+                    if positions {
+                        self.positions.push(pos.0.into());
+                    }
+                    if velocities {
+                        self.velocities.push(vel.0.into());
+                    }
+                    if speeds {
+                        self.speeds.push(speed.0.into());
+                    }
+                    if densities {
+                        self.densities.push(density.value);
+                    }
+                    if !state {
+                        return;
+                    }
+                    // Defaults stand in for missing state components to keep the columns aligned.
+                    let (fish_stress, grouped, target_vel, target_speed, rule): (
+                        f32,
+                        bool,
+                        [f32; 2],
+                        f32,
+                        Rule,
+                    ) = match (
+                        &stress_view,
+                        &social_view,
+                        &target_velocity_view,
+                        &target_speed_view,
+                    )
+                        .get(id)
+                    {
+                        Ok((fish_stress, social, target_vel, target_speed)) => (
+                            fish_stress.0.into(),
+                            social.is_in_group,
+                            target_vel.0.into(),
+                            target_speed.0.into(),
+                            social.rule,
+                        ),
+                        Err(_) => (0.0, false, [0.0, 0.0], 0.0, Rule::Idle),
+                    };
+                    if stress {
+                        self.stress.push(fish_stress);
+                    }
+                    if is_in_group {
+                        self.is_in_group.push(grouped);
+                    }
+                    if target_velocities {
+                        self.target_velocities.push(target_vel);
+                    }
+                    if target_speeds {
+                        self.target_speeds.push(target_speed);
+                    }
+                    if rules {
+                        self.rules.push(rule);
+                    }
+                });
+        }
 
-        Self {
-            ids,
-            positions,
-            velocities,
-            speeds,
-            densities,
+        if fields.contains(&OutputField::Predators) {
+            (&position_view, &velocity_view, &predator_view)
+                .iter()
+                .for_each(|(pos, vel, _)| {
+                    self.predator_positions.push(pos.0.into());
+                    self.predator_velocities.push(vel.0.into());
+                });
         }
     }
 }
@@ -14,14 +14,14 @@
  * limitations under the License.
  */
 
-use crate::DeltaTime;
-use shipyard::UniqueViewMut;
+use crate::{Config, DeltaTime};
+use shipyard::{UniqueView, UniqueViewMut};
 
 #[derive(Debug)]
 pub struct CalculateDeltaTime;
 
 impl CalculateDeltaTime {
-    pub fn system(mut delta_time: UniqueViewMut<DeltaTime>) {
-        delta_time.calc();
+    pub fn system(cfg: UniqueView<Config>, mut delta_time: UniqueViewMut<DeltaTime>) {
+        delta_time.set(cfg.time_step);
     }
 }
@@ -0,0 +1,51 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    EnabledSystems, Position, PredatorIdentifier, SimulationSystem, Speed, Threat, Threats,
+    Velocity,
+};
+use shipyard::{IntoIter, UniqueView, UniqueViewMut, View};
+
+#[derive(Debug)]
+pub struct DetectThreats;
+
+impl DetectThreats {
+    pub fn system(
+        positions: View<Position>,
+        velocities: View<Velocity>,
+        speeds: View<Speed>,
+        predators: View<PredatorIdentifier>,
+        systems: UniqueView<EnabledSystems>,
+        mut threats: UniqueViewMut<Threats>,
+    ) {
+        threats.0.clear();
+
+        if !systems.is_enabled(SimulationSystem::Escape) {
+            return;
+        }
+
+        (&positions, &velocities, &speeds, &predators)
+            .iter()
+            .for_each(|(pos, vel, speed, _)| {
+                threats.0.push(Threat {
+                    position: pos.0,
+                    direction: vel.0,
+                    speed: speed.0.value,
+                });
+            });
+    }
+}
@@ -0,0 +1,150 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    Chunks, Clock, Config, EventKind, EventState, Events, FishIdentifier, Position,
+    PredatorIdentifier, Rule, SimulationEvent, Social, Vec2, algo::Clustering,
+};
+use shipyard::{EntityId, Get, IntoIter, UniqueView, UniqueViewMut, View, ViewMut};
+use std::collections::{BTreeMap, BTreeSet};
+
+const CAPTURE_DISTANCE: f32 = 5.0;
+
+#[derive(Debug)]
+pub struct EmitEvents;
+
+impl EmitEvents {
+    #[allow(clippy::too_many_arguments)]
+    pub fn system(
+        fish: View<FishIdentifier>,
+        positions: View<Position>,
+        socials: View<Social>,
+        predators: View<PredatorIdentifier>,
+        mut states: ViewMut<EventState>,
+        chunks: UniqueView<Chunks>,
+        cfg: UniqueView<Config>,
+        clock: UniqueView<Clock>,
+        mut events: UniqueViewMut<Events>,
+    ) {
+        let tick: u64 = clock.ticks();
+        let detect_splits: bool = events.is_enabled(EventKind::ClusterSplit);
+
+        let hunters: Vec<Vec2> = if events.is_enabled(EventKind::Captured) {
+            (&positions, &predators)
+                .iter()
+                .map(|(pos, _)| pos.0)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        for (fish, pos, social, state) in (&fish, &positions, &socials, &mut states).iter() {
+            let fish: usize = fish.0;
+
+            if social.rule == Rule::Avoidance && state.rule != Rule::Avoidance {
+                events.push(SimulationEvent::EnteredAvoidance { tick, fish });
+            }
+
+            if social.is_in_group && !state.is_in_group {
+                events.push(SimulationEvent::JoinedGroup { tick, fish });
+            } else if !social.is_in_group && state.is_in_group {
+                events.push(SimulationEvent::LeftGroup { tick, fish });
+            }
+
+            let is_captured: bool = hunters.iter().any(|&hunter| {
+                hunter.distance_squared(pos.0) <= CAPTURE_DISTANCE * CAPTURE_DISTANCE
+            });
+            if is_captured && !state.is_captured {
+                events.push(SimulationEvent::Captured { tick, fish });
+            }
+
+            state.is_in_group = social.is_in_group;
+            state.rule = social.rule;
+            state.is_captured = is_captured;
+            if !detect_splits {
+                state.cluster = None;
+            }
+        }
+
+        if detect_splits {
+            Self::detect_splits(
+                tick,
+                &fish,
+                &positions,
+                &mut states,
+                &chunks,
+                cfg.attraction_radius,
+                &mut events,
+            );
+        }
+    }
+
+    fn detect_splits(
+        tick: u64,
+        fish: &View<FishIdentifier>,
+        positions: &View<Position>,
+        states: &mut ViewMut<EventState>,
+        chunks: &Chunks,
+        radius: f32,
+        events: &mut Events,
+    ) {
+        let mut ids: Vec<EntityId> = Vec::new();
+        let mut fish_ids: Vec<usize> = Vec::new();
+        let mut fish_positions: Vec<Vec2> = Vec::new();
+        let mut previous: Vec<Option<usize>> = Vec::new();
+
+        (fish, positions, &*states)
+            .iter()
+            .with_id()
+            .for_each(|(id, (fish, pos, state))| {
+                ids.push(id);
+                fish_ids.push(fish.0);
+                fish_positions.push(pos.0);
+                previous.push(state.cluster);
+            });
+
+        // The shared index was built before this tick's motion, so the
+        // clusters are labelled from an index of the current positions.
+        let mut index: Chunks = Chunks::new(chunks.chunk_size());
+        index.build(fish_positions.iter().copied().zip(ids.iter().copied()));
+
+        let labels: Vec<usize> =
+            Clustering::label(&ids, &fish_ids, &fish_positions, &index, radius);
+
+        let mut parts: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+        for (&cluster, &label) in previous.iter().zip(&labels) {
+            if let Some(cluster) = cluster {
+                parts.entry(cluster).or_default().insert(label);
+            }
+        }
+
+        for (cluster, labels) in parts {
+            if labels.len() > 1 {
+                events.push(SimulationEvent::ClusterSplit {
+                    tick,
+                    cluster,
+                    parts: labels.len(),
+                });
+            }
+        }
+
+        for (&id, label) in ids.iter().zip(labels) {
+            if let Ok(mut state) = (&mut *states).get(id) {
+                state.cluster = Some(label);
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{Config, DeltaTime, Escape, Speed, TargetSpeed, TargetVelocity, Velocity};
+use rayon::prelude::*;
+use shipyard::{IntoIter, UniqueView, View, ViewMut};
+
+#[derive(Debug)]
+pub struct FastStart;
+
+impl FastStart {
+    pub fn system(
+        mut escapes: ViewMut<Escape>,
+        mut velocities: ViewMut<Velocity>,
+        target_velocities: View<TargetVelocity>,
+        mut speeds: ViewMut<Speed>,
+        target_speeds: View<TargetSpeed>,
+        cfg: UniqueView<Config>,
+        delta_time: UniqueView<DeltaTime>,
+    ) {
+        let elapsed: f32 = delta_time.delta().as_secs_f32();
+
+        (
+            &mut escapes,
+            &mut velocities,
+            &target_velocities,
+            &mut speeds,
+            &target_speeds,
+        )
+            .par_iter()
+            .for_each(|(escape, vel, target_vel, speed, target_speed)| {
+                if escape.fast_start {
+                    vel.0 = target_vel.0;
+                    speed.0 = target_speed.0;
+                    escape.fast_start = false;
+                    escape.refractory = cfg.refractory_period;
+                } else {
+                    escape.recover(elapsed);
+                }
+            });
+    }
+}
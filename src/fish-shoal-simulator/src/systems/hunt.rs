@@ -0,0 +1,67 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    Config, DeltaTime, FishIdentifier, Position, PredatorIdentifier, Speed, Vec2, Velocity,
+};
+use shipyard::{IntoIter, UniqueView, View, ViewMut};
+
+const TURN_RATE: f32 = 2.0;
+const STRIKE_DISTANCE: f32 = 40.0;
+const STRIKE_FACTOR: f32 = 2.5;
+
+#[derive(Debug)]
+pub struct Hunt;
+
+impl Hunt {
+    pub fn system(
+        positions: View<Position>,
+        mut velocities: ViewMut<Velocity>,
+        mut speeds: ViewMut<Speed>,
+        predators: View<PredatorIdentifier>,
+        fish: View<FishIdentifier>,
+        cfg: UniqueView<Config>,
+        delta_time: UniqueView<DeltaTime>,
+    ) {
+        let dt: DeltaTime = *delta_time;
+
+        (&positions, &mut velocities, &mut speeds, &predators)
+            .iter()
+            .for_each(|(pos, vel, speed, _)| {
+                let prey: Option<Vec2> = (&positions, &fish)
+                    .iter()
+                    .map(|(prey_pos, _)| prey_pos.0)
+                    .min_by(|a, b| {
+                        pos.0
+                            .distance_squared(*a)
+                            .total_cmp(&pos.0.distance_squared(*b))
+                    });
+
+                speed.0.value = cfg.predator_speed;
+
+                let Some(prey) = prey else {
+                    return;
+                };
+
+                let to_prey: Vec2 = (prey - pos.0).normalized();
+                vel.0 = vel.0.lerp(to_prey, TURN_RATE * dt).normalized();
+
+                if pos.0.distance(prey) <= STRIKE_DISTANCE {
+                    speed.0.value *= STRIKE_FACTOR;
+                }
+            });
+    }
+}
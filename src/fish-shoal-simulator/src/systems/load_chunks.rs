@@ -14,8 +14,8 @@
  * limitations under the License.
  */
 
-use crate::{Chunks, Config, Position};
-use shipyard::{EntityId, IntoIter, UniqueView, UniqueViewMut, View};
+use crate::{Chunks, Config, FishIdentifier, Position};
+use shipyard::{IntoIter, UniqueView, UniqueViewMut, View};
 
 #[derive(Debug)]
 pub struct LoadChunks;
@@ -23,17 +23,22 @@ pub struct LoadChunks;
 impl LoadChunks {
     pub fn system(
         positions: View<Position>,
+        fish: View<FishIdentifier>,
         cfg: UniqueView<Config>,
         mut chunks: UniqueViewMut<Chunks>,
     ) {
-        chunks.clear();
-        chunks.resize(cfg.attraction_radius);
+        let radius: f32 = cfg.interaction_radius();
+        let chunk_size: f32 = match cfg.chunk_size {
+            Some(chunk_size) => Chunks::clamp_size(chunk_size, radius),
+            None => Chunks::auto_size(radius, fish.len(), cfg.width as f32 * cfg.height as f32),
+        };
 
-        (&positions)
-            .iter()
-            .with_id()
-            .for_each(|(id, pos): (EntityId, &Position)| {
-                chunks.store(&pos.0, id);
-            });
+        chunks.resize(chunk_size);
+        chunks.build(
+            (&positions, &fish)
+                .iter()
+                .with_id()
+                .map(|(id, (pos, _))| (pos.0, id)),
+        );
     }
 }
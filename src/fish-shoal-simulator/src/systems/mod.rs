@@ -14,7 +14,12 @@
  * limitations under the License.
  */
 
+mod advance_clock;
 mod calculate_delta_time;
+mod detect_threats;
+mod emit_events;
+mod fast_start;
+mod hunt;
 mod lerp_to_target;
 mod load_chunks;
 mod motion;
@@ -22,7 +27,12 @@ mod out_of_bound;
 mod random_behavior;
 mod swarming;
 
+pub use advance_clock::AdvanceClock;
 pub use calculate_delta_time::CalculateDeltaTime;
+pub use detect_threats::DetectThreats;
+pub use emit_events::EmitEvents;
+pub use fast_start::FastStart;
+pub use hunt::Hunt;
 pub use lerp_to_target::LerpToTarget;
 pub use load_chunks::LoadChunks;
 pub use motion::Motion;
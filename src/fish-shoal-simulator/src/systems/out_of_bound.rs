@@ -14,30 +14,109 @@
  * limitations under the License.
  */
 
-use crate::{Config, Position};
+use crate::{
+    Arena, Clock, Config, EventKind, Events, FishIdentifier, Position, SimulationEvent,
+    TargetVelocity, Vec2, Velocity,
+};
 use rayon::prelude::*;
-use shipyard::{IntoIter, UniqueView, ViewMut};
+use shipyard::{IntoIter, UniqueView, UniqueViewMut, View, ViewMut};
+
+const WALL_MARGIN: f32 = 2.0;
 
 #[derive(Debug)]
 pub struct OutOfBound;
 
 impl OutOfBound {
-    pub fn system(mut positions: ViewMut<Position>, cfg: UniqueView<Config>) {
+    pub fn system(
+        mut positions: ViewMut<Position>,
+        mut velocities: ViewMut<Velocity>,
+        mut target_velocities: ViewMut<TargetVelocity>,
+        fish: View<FishIdentifier>,
+        cfg: UniqueView<Config>,
+        clock: UniqueView<Clock>,
+        mut events: UniqueViewMut<Events>,
+    ) {
+        let origin: Vec2 = cfg.origin.into();
         let width: f32 = cfg.width as f32;
         let height: f32 = cfg.height as f32;
 
-        (&mut positions).par_iter().for_each(|pos| {
-            if pos.0.x <= 0.0 {
-                pos.0.x = width - 1.0;
-            } else if pos.0.x >= width {
-                pos.0.x = 1.0;
+        if events.is_enabled(EventKind::CrossedBoundary) && cfg.arena.is_bounded() {
+            let tick: u64 = clock.ticks();
+            for (fish, pos) in (&fish, &positions).iter() {
+                if Self::is_out(&cfg.arena, pos.0 - origin, width, height) {
+                    events.push(SimulationEvent::CrossedBoundary { tick, fish: fish.0 });
+                }
             }
+        }
+
+        if cfg.arena.is_wrapping() {
+            Self::wrap(&mut positions, origin, width, height);
+        } else if cfg.arena.is_bounded() {
+            Self::confine(
+                &cfg.arena,
+                &mut positions,
+                &mut velocities,
+                &mut target_velocities,
+                origin,
+                width,
+                height,
+            );
+        }
+    }
+
+    // The event and the wrap or confinement share this predicate, so they never disagree.
+    fn is_out(arena: &Arena, local: Vec2, width: f32, height: f32) -> bool {
+        if arena.is_wrapping() {
+            Self::wraps(local.x, width) || Self::wraps(local.y, height)
+        } else {
+            !arena.contains(local, width, height)
+        }
+    }
+
+    fn wraps(value: f32, size: f32) -> bool {
+        value <= 0.0 || value >= size
+    }
 
-            if pos.0.y <= 0.0 {
-                pos.0.y = height - 1.0;
-            } else if pos.0.y >= height {
-                pos.0.y = 1.0;
+    fn wrap(positions: &mut ViewMut<Position>, origin: Vec2, width: f32, height: f32) {
+        positions.par_iter().for_each(|pos| {
+            let local: Vec2 = pos.0 - origin;
+
+            if Self::wraps(local.x, width) {
+                pos.0.x = origin.x + if local.x <= 0.0 { width - 1.0 } else { 1.0 };
+            }
+
+            if Self::wraps(local.y, height) {
+                pos.0.y = origin.y + if local.y <= 0.0 { height - 1.0 } else { 1.0 };
             }
         });
     }
+
+    fn confine(
+        arena: &Arena,
+        positions: &mut ViewMut<Position>,
+        velocities: &mut ViewMut<Velocity>,
+        target_velocities: &mut ViewMut<TargetVelocity>,
+        origin: Vec2,
+        width: f32,
+        height: f32,
+    ) {
+        (&mut *positions, &mut *velocities)
+            .par_iter()
+            .for_each(|(pos, vel)| {
+                let mut local: Vec2 = pos.0 - origin;
+                if let Some(normal) = arena.confine(&mut local, width, height) {
+                    pos.0 = local + origin;
+                    vel.0 = Arena::slide(vel.0, normal);
+                }
+            });
+
+        (&*positions, target_velocities)
+            .par_iter()
+            .for_each(|(pos, target_vel)| {
+                let local: Vec2 = pos.0 - origin;
+                if let Some(normal) = arena.wall_normal(local, width, height, WALL_MARGIN) {
+                    target_vel.0 = Arena::slide(target_vel.0, normal);
+                }
+            });
+    }
 }
@@ -14,8 +14,11 @@
  * limitations under the License.
  */
 
-use crate::{Config, Social, Scalar, Speed, Stress, TargetSpeed, TargetVelocity, Vec2, Velocity};
-use rand::{rngs::ThreadRng, Rng};
+use crate::{
+    Config, RandomStream, Scalar, Social, Speed, Stress, TargetSpeed, TargetVelocity, Vec2,
+    Velocity,
+};
+use rand::{Rng, rngs::StdRng};
 use rayon::prelude::*;
 use shipyard::{IntoIter, UniqueView, View, ViewMut};
 
@@ -23,6 +26,7 @@ use shipyard::{IntoIter, UniqueView, View, ViewMut};
 pub struct RandomBehavior;
 
 impl RandomBehavior {
+    #[allow(clippy::too_many_arguments)]
     pub fn system(
         velocities: View<Velocity>,
         mut target_velocities: ViewMut<TargetVelocity>,
@@ -30,6 +34,7 @@ impl RandomBehavior {
         mut target_speeds: ViewMut<TargetSpeed>,
         mut stress: ViewMut<Stress>,
         socials: View<Social>,
+        mut streams: ViewMut<RandomStream>,
         cfg: UniqueView<Config>,
     ) {
         (
@@ -39,32 +44,35 @@ impl RandomBehavior {
             &mut target_speeds,
             &mut stress,
             &socials,
+            &mut streams,
         )
             .par_iter()
-            .for_each(|(vel, target_vel, speed, target_speed, stress, social)| {
-                if social.is_in_group {
-                    return;
-                }
+            .for_each(
+                |(vel, target_vel, speed, target_speed, stress, social, stream)| {
+                    if social.is_in_group {
+                        return;
+                    }
 
-                let mut rng: ThreadRng = rand::rng();
+                    let rng: &mut StdRng = &mut stream.0;
 
-                if vel.0 == target_vel.0 && rng.random_bool(cfg.direction_change_prob) {
-                    let random_direction: Vec2 = Vec2::random_dir(&mut rng);
-                    target_vel.0 = target_vel
-                        .0
-                        .lerp(random_direction, rng.random_range(0.0..1.0));
-                }
+                    if vel.0 == target_vel.0 && rng.random_bool(cfg.direction_change_prob) {
+                        let random_direction: Vec2 = Vec2::random_dir(rng);
+                        target_vel.0 = target_vel
+                            .0
+                            .lerp(random_direction, rng.random_range(0.0..1.0));
+                    }
 
-                if speed.0 == target_speed.0 && rng.random_bool(cfg.speed_change_prob) {
-                    let random_speed: Scalar = Scalar::new_random(&mut rng, 10.0..100.0);
-                    target_speed.0 = target_speed
-                        .0
-                        .lerp(random_speed, rng.random_range(0.0..1.0));
-                }
+                    if speed.0 == target_speed.0 && rng.random_bool(cfg.speed_change_prob) {
+                        let random_speed: Scalar = Scalar::new_random(rng, 10.0..100.0);
+                        target_speed.0 = target_speed
+                            .0
+                            .lerp(random_speed, rng.random_range(0.0..1.0));
+                    }
 
-                if rng.random_bool(cfg.stress_change_prob) {
-                    stress.0 = Scalar::new_random(&mut rng, 0.1..0.5);
-                }
-            });
+                    if rng.random_bool(cfg.stress_change_prob) {
+                        stress.0 = Scalar::new_random(rng, 0.1..0.5);
+                    }
+                },
+            );
     }
 }
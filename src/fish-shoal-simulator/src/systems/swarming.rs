@@ -15,28 +15,103 @@
  */
 
 use crate::{
-    algo::SchoolingMechanism, /* Scalar, */ Chunks, Config, Density, Position, Social, Stress,
-    TargetSpeed, TargetVelocity, Vec2,
+    Chunks, Config, Density, Escape, Position, Rule, Scalar, Social, Stress, TargetSpeed,
+    TargetVelocity, Threats, Vec2, algo::SchoolingMechanism,
 };
-use shipyard::{EntityId, IntoIter, UniqueView, View, ViewMut};
-use std::collections::{HashMap, HashSet};
+use rayon::prelude::*;
+use shipyard::{EntityId, Get, IntoIter, UniqueView, View, ViewMut};
 
-macro_rules! collect_components {
-    ($c:ident) => {
-        $c.iter().with_id().map(|(i, v)| (i, v.0)).collect()
-    };
+const NO_INDEX: usize = usize::MAX;
+
+#[derive(Debug)]
+struct Decision {
+    velocity: Vec2,
+    speed: Scalar,
+    stress: Scalar,
+    density: usize,
+    is_alone: bool,
+    rule: Rule,
+    fast_start: bool,
 }
 
-macro_rules! neighbors {
-    ($o:ident, $c:ident) => {
-        $o.iter().filter_map(|&i| Some((i, *$c.get(&i)?))).collect()
-    };
+#[derive(Debug, Default)]
+struct Neighbors {
+    candidates: Vec<(f32, usize)>,
+    positions: Vec<Vec2>,
+    velocities: Vec<Vec2>,
+}
+
+#[derive(Debug, Default)]
+struct Snapshot {
+    ids: Vec<EntityId>,
+    positions: Vec<Vec2>,
+    velocities: Vec<Vec2>,
+    speeds: Vec<Scalar>,
+    stress: Vec<Scalar>,
+    can_fast_start: Vec<bool>,
+    indices: Vec<usize>,
+}
+
+impl Snapshot {
+    fn push(
+        &mut self,
+        id: EntityId,
+        pos: Vec2,
+        vel: Vec2,
+        speed: Scalar,
+        stress: Scalar,
+        ready: bool,
+    ) {
+        let entity_index: usize = id.uindex();
+        if entity_index >= self.indices.len() {
+            self.indices.resize(entity_index + 1, NO_INDEX);
+        }
+        self.indices[entity_index] = self.ids.len();
+
+        self.ids.push(id);
+        self.positions.push(pos);
+        self.velocities.push(vel);
+        self.speeds.push(speed);
+        self.stress.push(stress);
+        self.can_fast_start.push(ready);
+    }
+
+    fn gather(
+        &self,
+        index: usize,
+        neighbors: &[EntityId],
+        radius: f32,
+        candidates: &mut Vec<(f32, usize)>,
+    ) {
+        let pos: Vec2 = self.positions[index];
+        let radius_squared: f32 = radius * radius;
+
+        for &other_id in neighbors {
+            if let Some(other) = self.index_of(other_id)
+                && other != index
+            {
+                let distance_squared: f32 = pos.distance_squared(self.positions[other]);
+                if distance_squared <= radius_squared {
+                    candidates.push((distance_squared, other));
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn index_of(&self, id: EntityId) -> Option<usize> {
+        match self.indices.get(id.uindex()) {
+            Some(&index) if index != NO_INDEX && self.ids[index] == id => Some(index),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct Swarming;
 
 impl Swarming {
+    #[allow(clippy::too_many_arguments)]
     pub fn system(
         positions: View<Position>,
         mut velocities: ViewMut<TargetVelocity>,
@@ -44,71 +119,148 @@ impl Swarming {
         mut stress: ViewMut<Stress>,
         mut densities: ViewMut<Density>,
         mut socials: ViewMut<Social>,
+        mut escapes: ViewMut<Escape>,
         chunks: UniqueView<Chunks>,
+        threats: UniqueView<Threats>,
         cfg: UniqueView<Config>,
     ) {
-        let others_positions: HashMap<EntityId, Vec2> = collect_components!(positions);
-        let others_velocities: HashMap<EntityId, Vec2> = collect_components!(velocities);
-        // let others_speeds: HashMap<EntityId, Scalar> = collect_components!(speeds);
-
-        (
-            &positions,
-            &mut velocities,
-            &mut speeds,
-            &mut stress,
-            &mut densities,
-            &mut socials,
-        )
+        let mut snapshot: Snapshot = Snapshot::default();
+        (&positions, &velocities, &speeds, &stress, &escapes)
             .iter()
             .with_id()
-            .for_each(|(id, (pos, vel, speed, stress, density, social))| {
-                let mut neighbors: HashSet<EntityId> = chunks.load_chunk(&pos.0);
-                neighbors.remove(&id);
+            .for_each(|(id, (pos, vel, speed, stress, escape))| {
+                snapshot.push(id, pos.0, vel.0, speed.0, stress.0, escape.is_ready());
+            });
 
-                density.set(neighbors.len());
-                if density.value < SchoolingMechanism::MAX_NEIGHBORS {
-                    neighbors.extend(chunks.load_neighbors(&pos.0));
-                }
-                neighbors.remove(&id);
-
-                density.set(neighbors.len());
-                if density.is_zero() {
-                    social.set_alone();
-                    speed.0.value = 50.0;
-                    stress.0.value = 0.1;
-                    return;
-                }
+        let decisions: Vec<Decision> = (0..snapshot.ids.len())
+            .into_par_iter()
+            .map_init(Neighbors::default, |neighbors: &mut Neighbors, index| {
+                Self::decide(&snapshot, index, neighbors, &chunks, &threats, &cfg)
+            })
+            .collect();
+
+        for (&id, decision) in snapshot.ids.iter().zip(decisions) {
+            let Ok((mut vel, mut speed, mut stress, mut density, mut social, mut escape)) = (
+                &mut velocities,
+                &mut speeds,
+                &mut stress,
+                &mut densities,
+                &mut socials,
+                &mut escapes,
+            )
+                .get(id)
+            else {
+                continue;
+            };
+
+            vel.0 = decision.velocity;
+            speed.0 = decision.speed;
+            stress.0 = decision.stress;
+            density.set(decision.density);
+            if decision.is_alone {
+                social.set_alone();
+            } else {
                 social.set_grouped();
+            }
+            social.set_rule(decision.rule);
+            if decision.fast_start {
+                escape.trigger();
+            }
+        }
+    }
 
-                let close_neighbors_count: usize = neighbors
-                    .iter()
-                    .filter(|&neighbor_id| {
-                        let neighbor_position: Vec2 = others_positions[neighbor_id];
-                        pos.0.distance(neighbor_position) <= cfg.alignment_radius
-                    })
-                    .count();
-                density.set(close_neighbors_count);
-
-                let mut algo: SchoolingMechanism = SchoolingMechanism::setup(
-                    pos.0,
-                    vel.0,
-                    speed.0,
-                    stress.0,
-                    neighbors!(neighbors, others_positions),
-                    neighbors!(neighbors, others_velocities),
-                    // neighbors!(neighbors, others_speeds),
-                    cfg.avoidance_radius,
-                    cfg.alignment_radius,
-                    cfg.attraction_radius,
-                );
-
-                if !algo.avoidance() {
-                    if !algo.alignment() {
-                        algo.attraction();
-                    }
-                }
+    fn decide(
+        snapshot: &Snapshot,
+        index: usize,
+        neighbors: &mut Neighbors,
+        chunks: &Chunks,
+        threats: &Threats,
+        cfg: &Config,
+    ) -> Decision {
+        let pos: Vec2 = snapshot.positions[index];
+        let radius: f32 = cfg.interaction_radius();
 
-                algo.set_behavior(&mut vel.0, &mut speed.0, &mut stress.0);
-            });
+        neighbors.candidates.clear();
+        neighbors.positions.clear();
+        neighbors.velocities.clear();
+
+        snapshot.gather(
+            index,
+            chunks.load_chunk(&pos),
+            radius,
+            &mut neighbors.candidates,
+        );
+        for cell in chunks.load_neighbors(&pos, radius) {
+            snapshot.gather(index, cell, radius, &mut neighbors.candidates);
+        }
+
+        // Nearest first, so the rules see the same neighbors whatever the chunk size.
+        neighbors
+            .candidates
+            .sort_unstable_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        for &(_, other) in &neighbors.candidates {
+            neighbors.positions.push(snapshot.positions[other]);
+            neighbors.velocities.push(snapshot.velocities[other]);
+        }
+        let others_positions: &[Vec2] = &neighbors.positions;
+        let others_velocities: &[Vec2] = &neighbors.velocities;
+
+        let is_alone: bool = others_positions.is_empty();
+        let density: usize = others_positions
+            .iter()
+            .filter(|&&other_position| pos.distance(other_position) <= cfg.alignment_radius)
+            .count();
+
+        let mut decision: Decision = Decision {
+            velocity: snapshot.velocities[index],
+            speed: snapshot.speeds[index],
+            stress: snapshot.stress[index],
+            density,
+            is_alone,
+            rule: Rule::Idle,
+            fast_start: false,
+        };
+
+        let mut algo: SchoolingMechanism = SchoolingMechanism::setup(
+            pos,
+            decision.velocity,
+            decision.speed,
+            decision.stress,
+            others_positions,
+            others_velocities,
+            cfg.avoidance_radius,
+            cfg.alignment_radius,
+            cfg.attraction_radius,
+        )
+        .with_threats(
+            &threats.0,
+            cfg.threat_radius,
+            cfg.escape_speed,
+            cfg.burst_speed,
+            snapshot.can_fast_start[index],
+        );
+
+        if algo.escape() {
+            decision.rule = Rule::Escape;
+        } else if is_alone {
+            decision.speed.value = 50.0;
+            decision.stress.value = 0.1;
+            return decision;
+        } else if algo.avoidance() {
+            decision.rule = Rule::Avoidance;
+        } else if algo.alignment() {
+            decision.rule = Rule::Alignment;
+        } else if algo.attraction() {
+            decision.rule = Rule::Attraction;
+        }
+
+        algo.set_behavior(
+            &mut decision.velocity,
+            &mut decision.speed,
+            &mut decision.stress,
+        );
+        decision.fast_start = algo.is_fast_start();
+
+        decision
     }
 }
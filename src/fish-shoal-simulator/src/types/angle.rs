@@ -200,7 +200,7 @@ impl Neg for Angle {
 mod tests {
     use super::Angle;
     use crate::Vec2;
-    use rand::{rngs::StdRng, SeedableRng};
+    use rand::{SeedableRng, rngs::StdRng};
     use std::f32::consts::{PI, TAU};
 
     #[test]
@@ -0,0 +1,310 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::Vec2;
+use rand::Rng;
+
+const SPAWN_ATTEMPTS: usize = 1_000;
+const CONFINE_ATTEMPTS: usize = 8;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum Arena {
+    #[default]
+    Rectangle,
+    Circle,
+    Polygon(Vec<Vec2>),
+    Open,
+}
+
+impl Arena {
+    #[inline]
+    pub fn circle(width: f32, height: f32) -> (Vec2, f32) {
+        (Vec2::new(width, height) / 2.0, width.min(height) / 2.0)
+    }
+
+    pub fn is_wrapping(&self) -> bool {
+        matches!(self, Self::Rectangle)
+    }
+
+    pub fn is_bounded(&self) -> bool {
+        !matches!(self, Self::Open)
+    }
+
+    pub fn contains(&self, pos: Vec2, width: f32, height: f32) -> bool {
+        match self {
+            Self::Rectangle => pos.x >= 0.0 && pos.x < width && pos.y >= 0.0 && pos.y < height,
+            Self::Circle => {
+                let (center, radius): (Vec2, f32) = Self::circle(width, height);
+                pos.distance_squared(center) <= radius * radius
+            }
+            Self::Polygon(vertices) => {
+                let mut inside: bool = false;
+
+                for (a, b) in Self::edges(vertices) {
+                    if (a.y > pos.y) != (b.y > pos.y)
+                        && pos.x < a.x + (pos.y - a.y) / (b.y - a.y) * (b.x - a.x)
+                    {
+                        inside = !inside;
+                    }
+                }
+
+                inside
+            }
+            Self::Open => true,
+        }
+    }
+
+    pub fn closest_boundary(&self, pos: Vec2, width: f32, height: f32) -> (Vec2, Vec2) {
+        match self {
+            Self::Rectangle => {
+                let mut closest: Vec2 = pos;
+                closest.clamp(Vec2::ZERO, Vec2::new(width, height));
+
+                let walls: [(f32, Vec2, Vec2); 4] = [
+                    (pos.x, Vec2::new(0.0, closest.y), Vec2::new(-1.0, 0.0)),
+                    (
+                        width - pos.x,
+                        Vec2::new(width, closest.y),
+                        Vec2::new(1.0, 0.0),
+                    ),
+                    (pos.y, Vec2::new(closest.x, 0.0), Vec2::new(0.0, -1.0)),
+                    (
+                        height - pos.y,
+                        Vec2::new(closest.x, height),
+                        Vec2::new(0.0, 1.0),
+                    ),
+                ];
+
+                walls
+                    .into_iter()
+                    .min_by(|a, b| a.0.total_cmp(&b.0))
+                    .map(|(_, point, normal)| (point, normal))
+                    .unwrap_or((closest, Vec2::ZERO))
+            }
+            Self::Circle => {
+                let (center, radius): (Vec2, f32) = Self::circle(width, height);
+                let mut normal: Vec2 = (pos - center).normalized();
+                if normal == Vec2::ZERO {
+                    normal = Vec2::new(1.0, 0.0);
+                }
+                (center + normal * radius, normal)
+            }
+            Self::Polygon(vertices) => {
+                let orientation: f32 = Self::signed_area(vertices).signum();
+
+                Self::edges(vertices)
+                    .map(|(a, b)| {
+                        let edge: Vec2 = b - a;
+                        let t: f32 = if edge == Vec2::ZERO {
+                            0.0
+                        } else {
+                            ((pos - a).dot(edge) / edge.length_squared()).clamp(0.0, 1.0)
+                        };
+                        let point: Vec2 = a + edge * t;
+                        let normal: Vec2 = -edge.perpendicular().normalized() * orientation;
+                        (pos.distance_squared(point), point, normal)
+                    })
+                    .min_by(|a, b| a.0.total_cmp(&b.0))
+                    .map(|(_, point, normal)| (point, normal))
+                    .unwrap_or((pos, Vec2::ZERO))
+            }
+            Self::Open => (pos, Vec2::ZERO),
+        }
+    }
+
+    pub fn confine(&self, pos: &mut Vec2, width: f32, height: f32) -> Option<Vec2> {
+        if self.contains(*pos, width, height) {
+            return None;
+        }
+
+        let (point, normal): (Vec2, Vec2) = self.closest_boundary(*pos, width, height);
+        *pos = point - normal;
+
+        for _ in 0..CONFINE_ATTEMPTS {
+            if self.contains(*pos, width, height) {
+                break;
+            }
+            let (point, normal): (Vec2, Vec2) = self.closest_boundary(*pos, width, height);
+            *pos = point - normal;
+        }
+
+        Some(normal)
+    }
+
+    pub fn wall_normal(&self, pos: Vec2, width: f32, height: f32, margin: f32) -> Option<Vec2> {
+        if !self.is_bounded() {
+            return None;
+        }
+
+        let (point, normal): (Vec2, Vec2) = self.closest_boundary(pos, width, height);
+
+        if !self.contains(pos, width, height) || pos.distance(point) <= margin {
+            Some(normal)
+        } else {
+            None
+        }
+    }
+
+    pub fn slide(direction: Vec2, normal: Vec2) -> Vec2 {
+        let outward: f32 = direction.dot(normal);
+        if outward <= 0.0 {
+            return direction;
+        }
+
+        let tangent: Vec2 = (direction - normal * outward).normalized();
+        if tangent == Vec2::ZERO {
+            -normal
+        } else {
+            tangent
+        }
+    }
+
+    pub fn random_position(&self, rng: &mut impl Rng, width: f32, height: f32) -> Vec2 {
+        let (min, max): (Vec2, Vec2) = self.bounds(width, height);
+
+        if min.x >= max.x || min.y >= max.y {
+            return min;
+        }
+
+        for _ in 0..SPAWN_ATTEMPTS {
+            let pos: Vec2 = Vec2::new_random(rng, min.x..max.x, min.y..max.y);
+            if self.contains(pos, width, height) {
+                return pos;
+            }
+        }
+
+        (min + max) / 2.0
+    }
+
+    pub fn bounds(&self, width: f32, height: f32) -> (Vec2, Vec2) {
+        match self {
+            Self::Rectangle | Self::Open => (Vec2::ZERO, Vec2::new(width, height)),
+            Self::Circle => {
+                let (center, radius): (Vec2, f32) = Self::circle(width, height);
+                (center - Vec2::ONE * radius, center + Vec2::ONE * radius)
+            }
+            Self::Polygon(vertices) => vertices.iter().fold(
+                (Vec2::new(f32::MAX, f32::MAX), Vec2::new(f32::MIN, f32::MIN)),
+                |(min, max), vertex| {
+                    (
+                        Vec2::new(min.x.min(vertex.x), min.y.min(vertex.y)),
+                        Vec2::new(max.x.max(vertex.x), max.y.max(vertex.y)),
+                    )
+                },
+            ),
+        }
+    }
+
+    fn edges(vertices: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .map(|(&a, &b)| (a, b))
+    }
+
+    pub(crate) fn signed_area(vertices: &[Vec2]) -> f32 {
+        Self::edges(vertices)
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum::<f32>()
+            / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Arena;
+    use crate::Vec2;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    fn mock_triangle() -> Arena {
+        Arena::Polygon(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(100.0, 0.0),
+            Vec2::new(0.0, 100.0),
+        ])
+    }
+
+    #[test]
+    fn arena_contains() {
+        let circle: Arena = Arena::Circle;
+        assert!(circle.contains(Vec2::new(50.0, 50.0), 100.0, 100.0));
+        assert!(!circle.contains(Vec2::new(5.0, 5.0), 100.0, 100.0));
+
+        let triangle: Arena = mock_triangle();
+        assert!(triangle.contains(Vec2::new(10.0, 10.0), 100.0, 100.0));
+        assert!(!triangle.contains(Vec2::new(90.0, 90.0), 100.0, 100.0));
+
+        let open: Arena = Arena::Open;
+        assert!(open.contains(Vec2::new(-1.0e6, 1.0e6), 100.0, 100.0));
+    }
+
+    #[test]
+    fn arena_confine() {
+        let circle: Arena = Arena::Circle;
+        let mut pos: Vec2 = Vec2::new(120.0, 50.0);
+
+        let normal: Option<Vec2> = circle.confine(&mut pos, 100.0, 100.0);
+
+        assert_eq!(normal, Some(Vec2::new(1.0, 0.0)));
+        assert!(circle.contains(pos, 100.0, 100.0));
+
+        let triangle: Arena = mock_triangle();
+        let mut pos: Vec2 = Vec2::new(60.0, 60.0);
+
+        let normal: Vec2 = triangle
+            .confine(&mut pos, 100.0, 100.0)
+            .expect("Position should be outside the triangle");
+
+        assert!(triangle.contains(pos, 100.0, 100.0));
+        assert!(normal.x > 0.0 && normal.y > 0.0);
+
+        let open: Arena = Arena::Open;
+        let mut pos: Vec2 = Vec2::new(-500.0, 500.0);
+
+        assert_eq!(open.confine(&mut pos, 100.0, 100.0), None);
+        assert_eq!(open.wall_normal(pos, 100.0, 100.0, 2.0), None);
+        assert_eq!(pos, Vec2::new(-500.0, 500.0));
+    }
+
+    #[test]
+    fn arena_slide() {
+        let normal: Vec2 = Vec2::new(1.0, 0.0);
+
+        assert_eq!(
+            Arena::slide(Vec2::new(-1.0, 0.0), normal),
+            Vec2::new(-1.0, 0.0)
+        );
+        assert_eq!(
+            Arena::slide(Vec2::new(1.0, 1.0), normal),
+            Vec2::new(0.0, 1.0)
+        );
+        assert_eq!(
+            Arena::slide(Vec2::new(1.0, 0.0), normal),
+            Vec2::new(-1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn arena_random_position() {
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let triangle: Arena = mock_triangle();
+
+        for _ in 0..100 {
+            let pos: Vec2 = triangle.random_position(&mut rng, 100.0, 100.0);
+            assert!(triangle.contains(pos, 100.0, 100.0));
+        }
+    }
+}
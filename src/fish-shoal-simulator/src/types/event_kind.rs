@@ -0,0 +1,47 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EventKind {
+    EnteredAvoidance,
+    JoinedGroup,
+    LeftGroup,
+    Captured,
+    CrossedBoundary,
+    ClusterSplit,
+}
+
+impl EventKind {
+    pub const ALL: [Self; 6] = [
+        Self::EnteredAvoidance,
+        Self::JoinedGroup,
+        Self::LeftGroup,
+        Self::Captured,
+        Self::CrossedBoundary,
+        Self::ClusterSplit,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::EnteredAvoidance => "Entered avoidance",
+            Self::JoinedGroup => "Joined group",
+            Self::LeftGroup => "Left group",
+            Self::Captured => "Captured",
+            Self::CrossedBoundary => "Crossed boundary",
+            Self::ClusterSplit => "Cluster split",
+        }
+    }
+}
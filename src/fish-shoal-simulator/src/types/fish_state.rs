@@ -0,0 +1,31 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::Vec2;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct FishState {
+    pub id: usize,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub speed: f32,
+    pub target_velocity: Vec2,
+    pub target_speed: f32,
+    pub stress: f32,
+    pub density: usize,
+    pub is_in_group: bool,
+    pub refractory: f32,
+}
@@ -15,11 +15,33 @@
  */
 
 mod angle;
+mod arena;
+mod event_kind;
+mod fish_state;
+mod output_field;
 mod radius;
+mod removal_policy;
+mod resize_policy;
+mod rule;
 mod scalar;
+mod simulation_event;
+mod simulation_system;
+mod spawn_pattern;
+mod stage;
 mod vec2;
 
 pub use angle::Angle;
+pub use arena::Arena;
+pub use event_kind::EventKind;
+pub use fish_state::FishState;
+pub use output_field::OutputField;
 pub use radius::Radius;
+pub use removal_policy::RemovalPolicy;
+pub use resize_policy::ResizePolicy;
+pub use rule::Rule;
 pub use scalar::Scalar;
+pub use simulation_event::SimulationEvent;
+pub use simulation_system::SimulationSystem;
+pub use spawn_pattern::SpawnPattern;
+pub use stage::Stage;
 pub use vec2::Vec2;
@@ -0,0 +1,72 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum OutputField {
+    Ids,
+    Positions,
+    Velocities,
+    Speeds,
+    Densities,
+    Predators,
+    Stress,
+    IsInGroup,
+    TargetVelocities,
+    TargetSpeeds,
+    Rules,
+}
+
+impl OutputField {
+    pub const ALL: [Self; 11] = [
+        Self::Ids,
+        Self::Positions,
+        Self::Velocities,
+        Self::Speeds,
+        Self::Densities,
+        Self::Predators,
+        Self::Stress,
+        Self::IsInGroup,
+        Self::TargetVelocities,
+        Self::TargetSpeeds,
+        Self::Rules,
+    ];
+
+    // The per-fish state fields are opt-in.
+    pub const DEFAULT: [Self; 6] = [
+        Self::Ids,
+        Self::Positions,
+        Self::Velocities,
+        Self::Speeds,
+        Self::Densities,
+        Self::Predators,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ids => "IDs",
+            Self::Positions => "Positions",
+            Self::Velocities => "Velocities",
+            Self::Speeds => "Speeds",
+            Self::Densities => "Densities",
+            Self::Predators => "Predators",
+            Self::Stress => "Stress",
+            Self::IsInGroup => "In group",
+            Self::TargetVelocities => "Target velocities",
+            Self::TargetSpeeds => "Target speeds",
+            Self::Rules => "Rules",
+        }
+    }
+}
@@ -0,0 +1,44 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::Vec2;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum RemovalPolicy {
+    #[default]
+    Random,
+    Oldest,
+    Newest,
+    Region {
+        min: Vec2,
+        max: Vec2,
+    },
+    Ids(Vec<usize>),
+    MostIsolated,
+}
+
+impl RemovalPolicy {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Random => "Random",
+            Self::Oldest => "Oldest",
+            Self::Newest => "Newest",
+            Self::Region { .. } => "Region",
+            Self::Ids(_) => "IDs",
+            Self::MostIsolated => "Most isolated",
+        }
+    }
+}
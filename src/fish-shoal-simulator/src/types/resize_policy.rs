@@ -0,0 +1,101 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{Arena, Vec2};
+use rand::Rng;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResizePolicy {
+    #[default]
+    Scale,
+    Clamp,
+    Respawn,
+}
+
+impl ResizePolicy {
+    pub const ALL: [Self; 3] = [Self::Scale, Self::Clamp, Self::Respawn];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Scale => "Scale",
+            Self::Clamp => "Clamp",
+            Self::Respawn => "Respawn",
+        }
+    }
+
+    // Positions are relative to the area origin, and the result always lies inside the new arena.
+    pub fn relocate(
+        &self,
+        rng: &mut impl Rng,
+        arena: &Arena,
+        pos: Vec2,
+        from: Vec2,
+        to: Vec2,
+    ) -> Vec2 {
+        let mut pos: Vec2 = pos;
+
+        match self {
+            Self::Scale => {
+                if !matches!(arena, Arena::Polygon(_)) && from.x > 0.0 && from.y > 0.0 {
+                    pos = Vec2::new(pos.x * to.x / from.x, pos.y * to.y / from.y);
+                }
+                arena.confine(&mut pos, to.x, to.y);
+            }
+            Self::Clamp => {
+                arena.confine(&mut pos, to.x, to.y);
+            }
+            Self::Respawn => {
+                if !arena.contains(pos, to.x, to.y) {
+                    pos = arena.random_position(rng, to.x, to.y);
+                }
+            }
+        }
+
+        pos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    #[test]
+    fn resize_policy_relocate() {
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let from: Vec2 = Vec2::new(200.0, 100.0);
+        let to: Vec2 = Vec2::new(100.0, 200.0);
+        let arena: Arena = Arena::Rectangle;
+
+        let scaled: Vec2 =
+            ResizePolicy::Scale.relocate(&mut rng, &arena, Vec2::new(150.0, 50.0), from, to);
+        assert_eq!(scaled, Vec2::new(75.0, 100.0));
+
+        let inside: Vec2 = Vec2::new(50.0, 50.0);
+        for policy in [ResizePolicy::Clamp, ResizePolicy::Respawn] {
+            assert_eq!(policy.relocate(&mut rng, &arena, inside, from, to), inside);
+        }
+
+        let outside: Vec2 = Vec2::new(150.0, 50.0);
+        for policy in ResizePolicy::ALL {
+            let pos: Vec2 = policy.relocate(&mut rng, &arena, outside, from, to);
+            assert!(arena.contains(pos, to.x, to.y), "{}: {pos}", policy.name());
+        }
+
+        let clamped: Vec2 = ResizePolicy::Clamp.relocate(&mut rng, &arena, outside, from, to);
+        assert!(clamped.x > 95.0 && clamped.y == 50.0);
+    }
+}
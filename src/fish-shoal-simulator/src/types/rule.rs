@@ -0,0 +1,45 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Rule {
+    #[default]
+    Idle,
+    Escape,
+    Avoidance,
+    Alignment,
+    Attraction,
+}
+
+impl Rule {
+    pub const ALL: [Self; 5] = [
+        Self::Idle,
+        Self::Escape,
+        Self::Avoidance,
+        Self::Alignment,
+        Self::Attraction,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Idle => "Idle",
+            Self::Escape => "Escape",
+            Self::Avoidance => "Avoidance",
+            Self::Alignment => "Alignment",
+            Self::Attraction => "Attraction",
+        }
+    }
+}
@@ -184,7 +184,7 @@ impl Neg for Scalar {
 #[cfg(test)]
 mod tests {
     use super::Scalar;
-    use rand::{rngs::StdRng, SeedableRng};
+    use rand::{SeedableRng, rngs::StdRng};
 
     #[test]
     fn scalar_ops() {
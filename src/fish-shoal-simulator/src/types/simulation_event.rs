@@ -0,0 +1,71 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::EventKind;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SimulationEvent {
+    EnteredAvoidance {
+        tick: u64,
+        fish: usize,
+    },
+    JoinedGroup {
+        tick: u64,
+        fish: usize,
+    },
+    LeftGroup {
+        tick: u64,
+        fish: usize,
+    },
+    Captured {
+        tick: u64,
+        fish: usize,
+    },
+    CrossedBoundary {
+        tick: u64,
+        fish: usize,
+    },
+    /// `cluster` is the smallest fish identifier of the cluster before it split.
+    ClusterSplit {
+        tick: u64,
+        cluster: usize,
+        parts: usize,
+    },
+}
+
+impl SimulationEvent {
+    pub fn kind(&self) -> EventKind {
+        match self {
+            Self::EnteredAvoidance { .. } => EventKind::EnteredAvoidance,
+            Self::JoinedGroup { .. } => EventKind::JoinedGroup,
+            Self::LeftGroup { .. } => EventKind::LeftGroup,
+            Self::Captured { .. } => EventKind::Captured,
+            Self::CrossedBoundary { .. } => EventKind::CrossedBoundary,
+            Self::ClusterSplit { .. } => EventKind::ClusterSplit,
+        }
+    }
+
+    pub fn tick(&self) -> u64 {
+        match *self {
+            Self::EnteredAvoidance { tick, .. }
+            | Self::JoinedGroup { tick, .. }
+            | Self::LeftGroup { tick, .. }
+            | Self::Captured { tick, .. }
+            | Self::CrossedBoundary { tick, .. }
+            | Self::ClusterSplit { tick, .. } => tick,
+        }
+    }
+}
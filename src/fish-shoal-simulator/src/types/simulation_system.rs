@@ -0,0 +1,44 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SimulationSystem {
+    Boundary,
+    RandomBehavior,
+    Hunt,
+    Escape,
+    Swarming,
+}
+
+impl SimulationSystem {
+    pub const ALL: [Self; 5] = [
+        Self::Boundary,
+        Self::RandomBehavior,
+        Self::Hunt,
+        Self::Escape,
+        Self::Swarming,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Boundary => "Boundary",
+            Self::RandomBehavior => "Random behavior",
+            Self::Hunt => "Hunt",
+            Self::Escape => "Escape",
+            Self::Swarming => "Swarming",
+        }
+    }
+}
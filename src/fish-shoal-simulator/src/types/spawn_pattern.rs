@@ -0,0 +1,399 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{Angle, Arena, Vec2};
+use rand::Rng;
+use std::f32::consts::TAU;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum SpawnPattern {
+    #[default]
+    Uniform,
+    Ball {
+        center: Vec2,
+        radius: f32,
+    },
+    Block {
+        center: Vec2,
+        size: Vec2,
+        heading: Vec2,
+    },
+    Mill {
+        center: Vec2,
+        inner_radius: f32,
+        outer_radius: f32,
+        clockwise: bool,
+    },
+    Ring {
+        center: Vec2,
+        radius: f32,
+    },
+    Grid {
+        center: Vec2,
+        spacing: f32,
+    },
+    Clusters {
+        count: usize,
+        radius: f32,
+    },
+    Point {
+        position: Vec2,
+        heading: Vec2,
+        spread: f32,
+    },
+}
+
+impl SpawnPattern {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Uniform => "Uniform",
+            Self::Ball { .. } => "Ball",
+            Self::Block { .. } => "Polarized block",
+            Self::Mill { .. } => "Mill",
+            Self::Ring { .. } => "Ring",
+            Self::Grid { .. } => "Grid",
+            Self::Clusters { .. } => "Clusters",
+            Self::Point { .. } => "Point",
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let finite = |values: &[f32]| values.iter().all(|value| value.is_finite());
+
+        match self {
+            Self::Uniform => Ok(()),
+            Self::Ball { center, radius } | Self::Ring { center, radius }
+                if !(center.is_finite() && finite(&[*radius]) && *radius >= 0.0) =>
+            {
+                Err("radius must not be negative")
+            }
+            Self::Block {
+                center,
+                size,
+                heading,
+            } if !(center.is_finite()
+                && heading.is_finite()
+                && size.is_finite()
+                && size.x >= 0.0
+                && size.y >= 0.0) =>
+            {
+                Err("block size must not be negative")
+            }
+            Self::Mill {
+                center,
+                inner_radius,
+                outer_radius,
+                ..
+            } if !(center.is_finite()
+                && finite(&[*inner_radius, *outer_radius])
+                && 0.0 <= *inner_radius
+                && inner_radius <= outer_radius) =>
+            {
+                Err("mill radii must satisfy 0 <= inner <= outer")
+            }
+            Self::Grid { center, spacing }
+                if !(center.is_finite() && finite(&[*spacing]) && *spacing > 0.0) =>
+            {
+                Err("grid spacing must be greater than zero")
+            }
+            Self::Clusters { count, radius }
+                if *count == 0 || !(finite(&[*radius]) && *radius >= 0.0) =>
+            {
+                Err("clusters need a count and a non-negative radius")
+            }
+            Self::Point {
+                position,
+                heading,
+                spread,
+            } if !(position.is_finite()
+                && heading.is_finite()
+                && (0.0..=360.0).contains(spread)) =>
+            {
+                Err("heading spread must be between 0 and 360 degrees")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Positions are in area coordinates, the world origin being added by the caller.
+    pub fn placements(
+        &self,
+        rng: &mut impl Rng,
+        amount: usize,
+        arena: &Arena,
+        width: f32,
+        height: f32,
+    ) -> Vec<(Vec2, Vec2)> {
+        match self {
+            Self::Uniform => (0..amount)
+                .map(|_| {
+                    let pos: Vec2 = arena.random_position(rng, width, height);
+                    (pos, Vec2::random_dir(rng))
+                })
+                .collect(),
+            Self::Ball { center, radius } => (0..amount)
+                .map(|_| {
+                    let pos: Vec2 = *center + Self::random_in_disk(rng, 0.0, *radius);
+                    (pos, Vec2::random_dir(rng))
+                })
+                .collect(),
+            Self::Block {
+                center,
+                size,
+                heading,
+            } => {
+                let half: Vec2 = *size / 2.0;
+                (0..amount)
+                    .map(|_| {
+                        let offset: Vec2 = Vec2::new(
+                            Self::random_in(rng, -half.x, half.x),
+                            Self::random_in(rng, -half.y, half.y),
+                        );
+                        (*center + offset, Self::heading(rng, *heading))
+                    })
+                    .collect()
+            }
+            Self::Mill {
+                center,
+                inner_radius,
+                outer_radius,
+                clockwise,
+            } => (0..amount)
+                .map(|_| {
+                    let offset: Vec2 = Self::random_in_disk(rng, *inner_radius, *outer_radius);
+                    let tangent: Vec2 = offset.normalized().perpendicular();
+                    let direction: Vec2 = if *clockwise { -tangent } else { tangent };
+                    (*center + offset, Self::heading(rng, direction))
+                })
+                .collect(),
+            Self::Ring { center, radius } => (0..amount)
+                .map(|index| {
+                    let angle: Angle = Angle::new(TAU * index as f32 / amount as f32);
+                    (*center + angle.to_vector() * *radius, Vec2::random_dir(rng))
+                })
+                .collect(),
+            Self::Grid { center, spacing } => {
+                let side: usize = (amount as f32).sqrt().ceil() as usize;
+                let half: f32 = side.saturating_sub(1) as f32 / 2.0;
+                (0..amount)
+                    .map(|index| {
+                        let cell: Vec2 = Vec2::new((index % side) as f32, (index / side) as f32);
+                        let offset: Vec2 = (cell - Vec2::new(half, half)) * *spacing;
+                        (*center + offset, Vec2::random_dir(rng))
+                    })
+                    .collect()
+            }
+            Self::Clusters { count: 0, .. } => Vec::new(),
+            Self::Clusters { count, radius } => {
+                let centers: Vec<Vec2> = (0..*count)
+                    .map(|_| arena.random_position(rng, width, height))
+                    .collect();
+                (0..amount)
+                    .map(|index| {
+                        let pos: Vec2 =
+                            centers[index % count] + Self::random_in_disk(rng, 0.0, *radius);
+                        (pos, Vec2::random_dir(rng))
+                    })
+                    .collect()
+            }
+            Self::Point {
+                position,
+                heading,
+                spread,
+            } => {
+                let half_spread: f32 = spread.to_radians() / 2.0;
+                (0..amount)
+                    .map(|_| {
+                        let mut angle: Angle = Angle::from_vector(Self::heading(rng, *heading));
+                        angle += Angle::new(Self::random_in(rng, -half_spread, half_spread));
+                        (*position, angle.to_vector())
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn heading(rng: &mut impl Rng, direction: Vec2) -> Vec2 {
+        if direction.length() > 0.0 {
+            direction.normalized()
+        } else {
+            Vec2::random_dir(rng)
+        }
+    }
+
+    fn random_in(rng: &mut impl Rng, min: f32, max: f32) -> f32 {
+        if min < max {
+            rng.random_range(min..max)
+        } else {
+            min
+        }
+    }
+
+    fn random_in_disk(rng: &mut impl Rng, inner_radius: f32, outer_radius: f32) -> Vec2 {
+        let (inner, outer): (f32, f32) = (inner_radius * inner_radius, outer_radius * outer_radius);
+        let radius: f32 = Self::random_in(rng, inner, outer).sqrt();
+        Vec2::random_dir(rng) * radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    const AMOUNT: usize = 50;
+
+    fn placements(pattern: &SpawnPattern) -> Vec<(Vec2, Vec2)> {
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let placements: Vec<(Vec2, Vec2)> =
+            pattern.placements(&mut rng, AMOUNT, &Arena::Rectangle, 1_000.0, 1_000.0);
+
+        assert_eq!(placements.len(), AMOUNT);
+        assert!(
+            placements
+                .iter()
+                .all(|(_, dir)| (dir.length() - 1.0).abs() < 1e-4)
+        );
+        placements
+    }
+
+    #[test]
+    fn spawn_pattern_ball_and_ring() {
+        let center: Vec2 = Vec2::new(100.0, 200.0);
+
+        let ball: Vec<(Vec2, Vec2)> = placements(&SpawnPattern::Ball {
+            center,
+            radius: 20.0,
+        });
+        assert!(
+            ball.iter()
+                .all(|(pos, _)| pos.distance(center) <= 20.0 + 1e-3)
+        );
+
+        let ring: Vec<(Vec2, Vec2)> = placements(&SpawnPattern::Ring {
+            center,
+            radius: 20.0,
+        });
+        assert!(
+            ring.iter()
+                .all(|(pos, _)| (pos.distance(center) - 20.0).abs() < 1e-3)
+        );
+    }
+
+    #[test]
+    fn spawn_pattern_block_and_mill() {
+        let center: Vec2 = Vec2::new(100.0, 200.0);
+
+        let block: Vec<(Vec2, Vec2)> = placements(&SpawnPattern::Block {
+            center,
+            size: Vec2::new(40.0, 20.0),
+            heading: Vec2::new(0.0, 3.0),
+        });
+        assert!(block.iter().all(|&(pos, dir)| {
+            (pos.x - center.x).abs() <= 20.0
+                && (pos.y - center.y).abs() <= 10.0
+                && dir == Vec2::new(0.0, 1.0)
+        }));
+
+        let mill: Vec<(Vec2, Vec2)> = placements(&SpawnPattern::Mill {
+            center,
+            inner_radius: 10.0,
+            outer_radius: 30.0,
+            clockwise: false,
+        });
+        assert!(mill.iter().all(|&(pos, dir)| {
+            let radial: Vec2 = pos - center;
+            (10.0 - 1e-3..=30.0 + 1e-3).contains(&radial.length())
+                && radial.normalized().dot(dir).abs() < 1e-4
+        }));
+    }
+
+    #[test]
+    fn spawn_pattern_grid_clusters_and_point() {
+        let grid: Vec<(Vec2, Vec2)> = placements(&SpawnPattern::Grid {
+            center: Vec2::ZERO,
+            spacing: 5.0,
+        });
+        assert_eq!(grid[0].0, Vec2::new(-17.5, -17.5));
+        assert_eq!(grid[1].0, Vec2::new(-12.5, -17.5));
+
+        let clusters: Vec<(Vec2, Vec2)> = placements(&SpawnPattern::Clusters {
+            count: 3,
+            radius: 10.0,
+        });
+        assert!(clusters[0].0.distance(clusters[3].0) <= 20.0 + 1e-3);
+
+        let heading: Vec2 = Vec2::new(1.0, 0.0);
+        let point: Vec2 = Vec2::new(7.0, 7.0);
+        let spawned: Vec<(Vec2, Vec2)> = placements(&SpawnPattern::Point {
+            position: point,
+            heading,
+            spread: 90.0,
+        });
+        assert!(
+            spawned
+                .iter()
+                .all(|&(pos, dir)| pos == point
+                    && dir.dot(heading) >= 45f32.to_radians().cos() - 1e-4)
+        );
+    }
+
+    #[test]
+    fn spawn_pattern_validate() {
+        assert!(SpawnPattern::Uniform.validate().is_ok());
+        assert!(
+            SpawnPattern::Mill {
+                center: Vec2::ZERO,
+                inner_radius: 20.0,
+                outer_radius: 10.0,
+                clockwise: true,
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            SpawnPattern::Clusters {
+                count: 0,
+                radius: 10.0,
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            SpawnPattern::Clusters {
+                count: 0,
+                radius: 10.0,
+            }
+            .placements(
+                &mut StdRng::seed_from_u64(42),
+                AMOUNT,
+                &Arena::Rectangle,
+                1_000.0,
+                1_000.0
+            )
+            .is_empty()
+        );
+        assert!(
+            SpawnPattern::Point {
+                position: Vec2::ZERO,
+                heading: Vec2::ZERO,
+                spread: 400.0,
+            }
+            .validate()
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,34 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Motion,
+    Boundary,
+    Behavior,
+}
+
+impl Stage {
+    pub const ALL: [Self; 3] = [Self::Motion, Self::Boundary, Self::Behavior];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Motion => "Motion",
+            Self::Boundary => "Boundary",
+            Self::Behavior => "Behavior",
+        }
+    }
+}
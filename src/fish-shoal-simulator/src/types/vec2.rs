@@ -60,6 +60,11 @@ impl Vec2 {
         self.x * self.x + self.y * self.y
     }
 
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
     #[inline]
     pub fn distance(self, other: Self) -> f32 {
         (self - other).length()
@@ -75,6 +80,14 @@ impl Vec2 {
         self.x * other.x + self.y * other.y
     }
 
+    #[inline]
+    pub fn perpendicular(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
     #[inline]
     pub fn lerp(self, to: Self, t: f32) -> Self {
         let t: f32 = t.clamp(0.0, 1.0);
@@ -237,7 +250,7 @@ impl Neg for Vec2 {
 #[cfg(test)]
 mod tests {
     use super::Vec2;
-    use rand::{rngs::StdRng, SeedableRng};
+    use rand::{SeedableRng, rngs::StdRng};
 
     #[test]
     fn vec2_ops() {
@@ -16,108 +16,173 @@
 
 use crate::Vec2;
 use shipyard::{EntityId, Unique};
-use std::collections::{HashMap, HashSet};
+
+const MIN_CHUNK_SIZE: f32 = 1.0;
+const TARGET_DENSITY: f32 = 4.0;
+const MAX_RINGS: f32 = 4.0;
 
 #[derive(Unique, Debug, Default)]
 pub struct Chunks {
     chunk_size: f32,
-    chunks: HashMap<u32, HashSet<EntityId>>,
+    offsets: Vec<usize>,
+    cells: Vec<(i32, i32)>,
+    entities: Vec<EntityId>,
+    staged: Vec<((i32, i32), EntityId)>,
+    sorted: Vec<((i32, i32), EntityId)>,
 }
 
 impl Chunks {
     pub fn new(chunk_size: f32) -> Self {
         Self {
-            chunk_size,
-            chunks: HashMap::new(),
+            chunk_size: chunk_size.max(MIN_CHUNK_SIZE),
+            ..Self::default()
         }
     }
 
-    pub fn clear(&mut self) {
-        self.chunks.clear();
+    pub fn auto_size(radius: f32, entity_count: usize, area: f32) -> f32 {
+        let radius: f32 = radius.max(MIN_CHUNK_SIZE);
+        if entity_count == 0 || area <= 0.0 {
+            return radius;
+        }
+
+        let chunk_size: f32 = (area * TARGET_DENSITY / entity_count as f32).sqrt();
+        chunk_size
+            .clamp(radius / MAX_RINGS, radius)
+            .max(MIN_CHUNK_SIZE)
     }
 
-    pub fn resize(&mut self, chunk_size: f32) {
-        self.chunk_size = chunk_size;
+    // Same ring cap as `auto_size`, so a tiny fixed size cannot make neighbor queries explode.
+    pub fn clamp_size(chunk_size: f32, radius: f32) -> f32 {
+        chunk_size.max(radius / MAX_RINGS).max(MIN_CHUNK_SIZE)
     }
 
-    pub fn store(&mut self, pos: &Vec2, id: EntityId) {
-        let chunk_id: u32 = self.chunk_id_from_pos(pos);
+    pub fn chunk_size(&self) -> f32 {
+        self.chunk_size
+    }
 
-        let is_unique: bool = self.chunks.entry(chunk_id).or_default().insert(id);
-        debug_assert!(is_unique, "Entity {id:?} already exists in chunk");
+    pub fn rings(&self, radius: f32) -> i32 {
+        (radius / self.chunk_size).ceil().max(1.0) as i32
     }
 
-    pub fn remove(&mut self, pos: &Vec2, id: EntityId) {
-        let chunk_id: u32 = self.chunk_id_from_pos(pos);
+    pub fn clear(&mut self) {
+        self.offsets.clear();
+        self.cells.clear();
+        self.entities.clear();
+        self.staged.clear();
+        self.sorted.clear();
+    }
 
-        if let Some(chunk) = self.chunks.get_mut(&chunk_id) {
-            let existed: bool = chunk.remove(&id);
-            debug_assert!(existed, "Entity {id:?} was not in chunk");
+    pub fn resize(&mut self, chunk_size: f32) {
+        self.chunk_size = chunk_size.max(MIN_CHUNK_SIZE);
+    }
 
-            if chunk.is_empty() {
-                self.chunks.remove(&chunk_id);
-            }
+    pub fn build<I>(&mut self, entities: I)
+    where
+        I: IntoIterator<Item = (Vec2, EntityId)>,
+    {
+        self.clear();
+
+        let chunk_size: f32 = self.chunk_size;
+        self.staged.extend(
+            entities
+                .into_iter()
+                .map(|(pos, id)| (Self::chunk_coords(chunk_size, &pos), id)),
+        );
+
+        let bucket_count: usize = (self.staged.len() * 2).next_power_of_two();
+        self.offsets.resize(bucket_count + 1, 0);
+
+        for &(cell, _) in &self.staged {
+            self.offsets[Self::bucket(cell, bucket_count)] += 1;
         }
-    }
 
-    pub fn load_chunk(&self, pos: &Vec2) -> HashSet<EntityId> {
-        let id: u32 = self.chunk_id_from_pos(pos);
+        for bucket in 1..bucket_count {
+            self.offsets[bucket] += self.offsets[bucket - 1];
+        }
+        self.offsets[bucket_count] = self.staged.len();
+
+        self.sorted
+            .resize(self.staged.len(), ((0, 0), EntityId::dead()));
+        for &(cell, id) in self.staged.iter().rev() {
+            let bucket: usize = Self::bucket(cell, bucket_count);
+            self.offsets[bucket] -= 1;
+            self.sorted[self.offsets[bucket]] = (cell, id);
+        }
 
-        if let Some(chunk) = self.chunks.get(&id) {
-            return chunk.clone();
+        for bucket in 0..bucket_count {
+            let (start, end): (usize, usize) = (self.offsets[bucket], self.offsets[bucket + 1]);
+            if end - start > 1 {
+                self.sorted[start..end].sort_by_key(|&(cell, _)| cell);
+            }
         }
 
-        HashSet::new()
+        self.cells.extend(self.sorted.iter().map(|&(cell, _)| cell));
+        self.entities.extend(self.sorted.iter().map(|&(_, id)| id));
     }
 
-    pub fn load_neighbors(&self, pos: &Vec2) -> HashSet<EntityId> {
-        let (chunk_x, chunk_y): (u32, u32) = self.chunk_coords(pos);
+    pub fn load_chunk(&self, pos: &Vec2) -> &[EntityId] {
+        self.cell(Self::chunk_coords(self.chunk_size, pos))
+    }
 
-        let mut data: HashSet<EntityId> = HashSet::new();
+    pub fn load_neighbors(&self, pos: &Vec2, radius: f32) -> impl Iterator<Item = &[EntityId]> {
+        let (chunk_x, chunk_y): (i32, i32) = Self::chunk_coords(self.chunk_size, pos);
+        let rings: i32 = self.rings(radius);
 
-        let x_start: i32 = if chunk_x == 0 { 0 } else { -1 };
-        let y_start: i32 = if chunk_y == 0 { 0 } else { -1 };
+        (-rings..=rings)
+            .flat_map(move |dy: i32| (-rings..=rings).map(move |dx: i32| (dx, dy)))
+            .filter(|&(dx, dy)| dx != 0 || dy != 0)
+            .filter_map(move |(dx, dy)| Some((chunk_x.checked_add(dx)?, chunk_y.checked_add(dy)?)))
+            .map(|cell: (i32, i32)| self.cell(cell))
+            .filter(|chunk: &&[EntityId]| !chunk.is_empty())
+    }
 
-        for dx in x_start..=1 {
-            for dy in y_start..=1 {
-                if dx == 0 && dy == 0 {
-                    continue;
-                }
+    /// Returns `None` when the area spans more cells than there are entities,
+    /// a linear scan being cheaper.
+    pub fn load_area(&self, min: &Vec2, max: &Vec2) -> Option<impl Iterator<Item = &[EntityId]>> {
+        let (min_x, min_y): (i32, i32) = Self::chunk_coords(self.chunk_size, min);
+        let (max_x, max_y): (i32, i32) = Self::chunk_coords(self.chunk_size, max);
 
-                let x: u32 = (chunk_x as i32 + dx) as u32;
-                let y: u32 = (chunk_y as i32 + dy) as u32;
+        let cell_count: u64 = (max_x as i64 - min_x as i64 + 1).max(0) as u64
+            * (max_y as i64 - min_y as i64 + 1).max(0) as u64;
+        if cell_count > self.entities.len() as u64 {
+            return None;
+        }
 
-                let chunk_id: u32 = Self::chunk_id_from_coords(x, y);
+        Some(
+            (min_y..=max_y)
+                .flat_map(move |y: i32| (min_x..=max_x).map(move |x: i32| (x, y)))
+                .map(|cell: (i32, i32)| self.cell(cell))
+                .filter(|chunk: &&[EntityId]| !chunk.is_empty()),
+        )
+    }
 
-                if let Some(chunk) = self.chunks.get(&chunk_id) {
-                    data.extend(chunk);
-                }
-            }
+    fn cell(&self, cell: (i32, i32)) -> &[EntityId] {
+        if self.entities.is_empty() {
+            return &[];
         }
 
-        data
-    }
+        let bucket: usize = Self::bucket(cell, self.offsets.len() - 1);
+        let (start, end): (usize, usize) = (self.offsets[bucket], self.offsets[bucket + 1]);
 
-    #[inline]
-    fn chunk_id_from_pos(&self, pos: &Vec2) -> u32 {
-        let (x, y): (u32, u32) = self.chunk_coords(pos);
-        Self::chunk_id_from_coords(x, y)
+        let cells: &[(i32, i32)] = &self.cells[start..end];
+        let first: usize = cells.partition_point(|&other| other < cell);
+        let last: usize = cells.partition_point(|&other| other <= cell);
+
+        &self.entities[start + first..start + last]
     }
 
     #[inline]
-    fn chunk_id_from_coords(x: u32, y: u32) -> u32 {
-        let sum: u32 = x + y;
-        (sum * (sum + 1)) / 2 + y
+    fn bucket((x, y): (i32, i32), bucket_count: usize) -> usize {
+        let hash: u64 = (x as u32 as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ^ (y as u32 as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+        (hash ^ (hash >> 32)) as usize & (bucket_count - 1)
     }
 
     #[inline]
-    fn chunk_coords(&self, pos: &Vec2) -> (u32, u32) {
-        debug_assert!(pos.x >= 0.0, "Position {pos} is negative in x");
-        debug_assert!(pos.y >= 0.0, "Position {pos} is negative in y");
-
+    fn chunk_coords(chunk_size: f32, pos: &Vec2) -> (i32, i32) {
         (
-            (pos.x / self.chunk_size).floor() as u32,
-            (pos.y / self.chunk_size).floor() as u32,
+            (pos.x / chunk_size).floor() as i32,
+            (pos.y / chunk_size).floor() as i32,
         )
     }
 }
@@ -127,19 +192,28 @@ mod tests {
     use super::Chunks;
     use crate::Vec2;
     use shipyard::EntityId;
-    use std::collections::HashSet;
 
     fn mock_id(index: u64) -> EntityId {
         EntityId::new_from_index_and_gen(index, 0)
     }
 
+    fn neighbors(chunks: &Chunks, pos: Vec2, radius: f32) -> Vec<EntityId> {
+        let mut neighbors: Vec<EntityId> = chunks
+            .load_neighbors(&pos, radius)
+            .flatten()
+            .copied()
+            .collect();
+        neighbors.sort();
+        neighbors
+    }
+
     #[test]
     fn chunk_new() {
         let chunk_size: f32 = 32.0;
         let chunks_repository: Chunks = Chunks::new(chunk_size);
 
         assert_eq!(chunks_repository.chunk_size, chunk_size);
-        assert!(chunks_repository.chunks.is_empty());
+        assert!(chunks_repository.entities.is_empty());
     }
 
     #[test]
@@ -149,11 +223,12 @@ mod tests {
         let entity_position: Vec2 = Vec2::new(5.0, 5.0);
         let entity_identifier: EntityId = mock_id(1);
 
-        chunks_repository.store(&entity_position, entity_identifier);
-        assert!(!chunks_repository.chunks.is_empty());
+        chunks_repository.build([(entity_position, entity_identifier)]);
+        assert!(!chunks_repository.entities.is_empty());
 
         chunks_repository.clear();
-        assert!(chunks_repository.chunks.is_empty());
+        assert!(chunks_repository.entities.is_empty());
+        assert!(chunks_repository.load_chunk(&entity_position).is_empty());
     }
 
     #[test]
@@ -168,79 +243,186 @@ mod tests {
     }
 
     #[test]
-    fn chunk_store() {
+    fn chunk_build() {
         let chunk_size: f32 = 10.0;
         let mut chunks_repository: Chunks = Chunks::new(chunk_size);
-        let entity_position: Vec2 = Vec2::new(15.0, 15.0);
-        let entity_identifier: EntityId = mock_id(42);
-
-        let expected_chunk_identifier: u32 = 4;
 
-        chunks_repository.store(&entity_position, entity_identifier);
+        chunks_repository.build([
+            (Vec2::new(15.0, 15.0), mock_id(1)),
+            (Vec2::new(5.0, 5.0), mock_id(2)),
+            (Vec2::new(12.0, 18.0), mock_id(3)),
+            (Vec2::new(25.0, 5.0), mock_id(4)),
+        ]);
 
-        let stored_chunk: &HashSet<EntityId> = chunks_repository
-            .chunks
-            .get(&expected_chunk_identifier)
-            .expect("Chunk should exist after storage");
-
-        assert!(stored_chunk.contains(&entity_identifier));
+        assert_eq!(chunks_repository.offsets.len(), 9);
+        assert_eq!(chunks_repository.entities.len(), 4);
+        assert_eq!(chunks_repository.cells.len(), 4);
     }
 
     #[test]
-    fn chunk_remove() {
+    fn chunk_load_chunk() {
         let chunk_size: f32 = 10.0;
         let mut chunks_repository: Chunks = Chunks::new(chunk_size);
-        let entity_position: Vec2 = Vec2::new(5.0, 5.0);
-        let entity_identifier: EntityId = mock_id(100);
-
-        chunks_repository.store(&entity_position, entity_identifier);
-        chunks_repository.remove(&entity_position, entity_identifier);
 
-        assert!(chunks_repository.chunks.is_empty());
+        chunks_repository.build([
+            (Vec2::new(15.0, 15.0), mock_id(1)),
+            (Vec2::new(5.0, 5.0), mock_id(2)),
+            (Vec2::new(12.0, 18.0), mock_id(3)),
+        ]);
+
+        assert_eq!(
+            chunks_repository.load_chunk(&Vec2::new(11.0, 11.0)),
+            &[mock_id(1), mock_id(3)]
+        );
+        assert_eq!(
+            chunks_repository.load_chunk(&Vec2::new(1.0, 1.0)),
+            &[mock_id(2)]
+        );
+        assert!(
+            chunks_repository
+                .load_chunk(&Vec2::new(1.0, 11.0))
+                .is_empty()
+        );
+        assert!(
+            chunks_repository
+                .load_chunk(&Vec2::new(500.0, 500.0))
+                .is_empty()
+        );
     }
 
     #[test]
-    fn chunk_load_chunk() {
-        // TODO
+    fn chunk_load_neighbors() {
+        let chunk_size: f32 = 10.0;
+        let mut chunks_repository: Chunks = Chunks::new(chunk_size);
+
+        chunks_repository.build([
+            (Vec2::new(15.0, 15.0), mock_id(1)),
+            (Vec2::new(5.0, 5.0), mock_id(2)),
+            (Vec2::new(25.0, 25.0), mock_id(3)),
+            (Vec2::new(35.0, 35.0), mock_id(4)),
+        ]);
+
+        assert_eq!(
+            neighbors(&chunks_repository, Vec2::new(15.0, 15.0), 10.0),
+            vec![mock_id(2), mock_id(3)]
+        );
+        assert_eq!(
+            neighbors(&chunks_repository, Vec2::new(5.0, 5.0), 10.0),
+            vec![mock_id(1)]
+        );
     }
 
     #[test]
-    fn chunk_load_neighbors() {
-        // TODO
+    fn chunk_load_area() {
+        let mut chunks_repository: Chunks = Chunks::new(10.0);
+
+        chunks_repository.build([
+            (Vec2::new(5.0, 15.0), mock_id(1)),
+            (Vec2::new(-5.0, 5.0), mock_id(2)),
+            (Vec2::new(25.0, 25.0), mock_id(3)),
+            (Vec2::new(35.0, 35.0), mock_id(4)),
+        ]);
+
+        let mut ids: Vec<EntityId> = chunks_repository
+            .load_area(&Vec2::new(-5.0, 5.0), &Vec2::new(5.0, 15.0))
+            .unwrap()
+            .flatten()
+            .copied()
+            .collect();
+        ids.sort();
+
+        assert_eq!(ids, vec![mock_id(1), mock_id(2)]);
+        assert!(
+            chunks_repository
+                .load_area(&Vec2::new(0.0, 0.0), &Vec2::new(100.0, 100.0))
+                .is_none()
+        );
     }
 
     #[test]
-    fn chunk_id_from_pos() {
+    fn chunk_negative_and_large_coords() {
         let chunk_size: f32 = 10.0;
-        let chunks_repository: Chunks = Chunks::new(chunk_size);
-        let entity_position: Vec2 = Vec2::new(12.0, 12.0);
-
-        let expected_identifier: u32 = 4;
-        let actual_identifier: u32 = chunks_repository.chunk_id_from_pos(&entity_position);
+        let mut chunks_repository: Chunks = Chunks::new(chunk_size);
 
-        assert_eq!(actual_identifier, expected_identifier);
+        chunks_repository.build([
+            (Vec2::new(-5.0, -5.0), mock_id(1)),
+            (Vec2::new(5.0, 5.0), mock_id(2)),
+            (Vec2::new(-15.0, 5.0), mock_id(3)),
+            (Vec2::new(1.0e6, -1.0e6), mock_id(4)),
+            (Vec2::new(1.0e6 + 10.0, -1.0e6), mock_id(5)),
+            (Vec2::new(f32::MAX, f32::MIN), mock_id(6)),
+        ]);
+
+        assert_eq!(
+            chunks_repository.load_chunk(&Vec2::new(-1.0, -1.0)),
+            &[mock_id(1)]
+        );
+        assert_eq!(
+            neighbors(&chunks_repository, Vec2::new(-5.0, -5.0), 10.0),
+            vec![mock_id(2), mock_id(3)]
+        );
+        assert_eq!(
+            neighbors(&chunks_repository, Vec2::new(1.0e6, -1.0e6), 10.0),
+            vec![mock_id(5)]
+        );
+        assert_eq!(
+            chunks_repository.load_chunk(&Vec2::new(f32::MAX, f32::MIN)),
+            &[mock_id(6)]
+        );
+        assert!(neighbors(&chunks_repository, Vec2::new(f32::MAX, f32::MIN), 10.0).is_empty());
     }
 
     #[test]
-    fn chunk_id_from_coords() {
-        let coordinate_x: u32 = 1;
-        let coordinate_y: u32 = 1;
-
-        let expected_identifier: u32 = 4;
-        let actual_identifier: u32 = Chunks::chunk_id_from_coords(coordinate_x, coordinate_y);
+    fn chunk_coords() {
+        let chunk_size: f32 = 16.0;
 
-        assert_eq!(actual_identifier, expected_identifier);
+        assert_eq!(
+            Chunks::chunk_coords(chunk_size, &Vec2::new(33.0, 15.0)),
+            (2, 0)
+        );
+        assert_eq!(
+            Chunks::chunk_coords(chunk_size, &Vec2::new(-1.0, -17.0)),
+            (-1, -2)
+        );
     }
 
     #[test]
-    fn chunk_coords() {
-        let chunk_size: f32 = 16.0;
-        let chunks_repository: Chunks = Chunks::new(chunk_size);
-        let entity_position: Vec2 = Vec2::new(33.0, 15.0);
+    fn chunk_rings() {
+        let chunk_size: f32 = 10.0;
+        let mut chunks_repository: Chunks = Chunks::new(chunk_size);
 
-        let expected_coordinates: (u32, u32) = (2, 0);
-        let actual_coordinates: (u32, u32) = chunks_repository.chunk_coords(&entity_position);
+        chunks_repository.build([
+            (Vec2::new(5.0, 5.0), mock_id(1)),
+            (Vec2::new(25.0, 5.0), mock_id(2)),
+            (Vec2::new(35.0, 5.0), mock_id(3)),
+        ]);
+
+        assert_eq!(chunks_repository.rings(5.0), 1);
+        assert_eq!(chunks_repository.rings(25.0), 3);
+        assert!(neighbors(&chunks_repository, Vec2::new(5.0, 5.0), 10.0).is_empty());
+        assert_eq!(
+            neighbors(&chunks_repository, Vec2::new(5.0, 5.0), 20.0),
+            vec![mock_id(2)]
+        );
+        assert_eq!(
+            neighbors(&chunks_repository, Vec2::new(5.0, 5.0), 25.0),
+            vec![mock_id(2), mock_id(3)]
+        );
+    }
 
-        assert_eq!(actual_coordinates, expected_coordinates);
+    #[test]
+    fn chunk_auto_size() {
+        assert_eq!(Chunks::auto_size(50.0, 0, 1_000_000.0), 50.0);
+        assert_eq!(Chunks::auto_size(50.0, 100, 1_000_000.0), 50.0);
+        assert_eq!(Chunks::auto_size(50.0, 10_000, 1_000_000.0), 20.0);
+        assert_eq!(Chunks::auto_size(50.0, 1_000_000, 1_000_000.0), 12.5);
+        assert_eq!(Chunks::auto_size(0.0, 100, 1_000_000.0), 1.0);
+    }
+
+    #[test]
+    fn chunk_clamp_size() {
+        assert_eq!(Chunks::clamp_size(1e-6, 50.0), 12.5);
+        assert_eq!(Chunks::clamp_size(20.0, 50.0), 20.0);
+        assert_eq!(Chunks::clamp_size(1e-6, 0.0), 1.0);
     }
 }
@@ -0,0 +1,153 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::DeltaTime;
+use shipyard::Unique;
+use std::time::{Duration, Instant};
+
+#[derive(Unique, Debug, Copy, Clone)]
+pub struct Clock {
+    last_time: Instant,
+    accumulator: Duration,
+    elapsed: Duration,
+    ticks: u64,
+}
+
+impl Clock {
+    pub fn frame_time(&mut self) -> Duration {
+        let now: Instant = Instant::now();
+        let frame_time: Duration = now - self.last_time;
+        self.last_time = now;
+        frame_time
+    }
+
+    pub fn resync(&mut self) {
+        self.last_time = Instant::now();
+    }
+
+    pub fn accumulate(
+        &mut self,
+        frame_time: Duration,
+        time_scale: f32,
+        time_step: DeltaTime,
+        max_sub_steps: usize,
+    ) -> usize {
+        let step: Duration = time_step.delta();
+        if step.is_zero() {
+            return 0;
+        }
+
+        let scaled: f64 = frame_time.as_secs_f64() * time_scale.max(0.0) as f64;
+        self.accumulator = self
+            .accumulator
+            .saturating_add(Duration::try_from_secs_f64(scaled).unwrap_or(Duration::MAX));
+
+        let mut steps: usize = 0;
+        while self.accumulator >= step && steps < max_sub_steps {
+            self.accumulator -= step;
+            steps += 1;
+        }
+
+        if self.accumulator >= step {
+            self.accumulator =
+                Duration::from_nanos((self.accumulator.as_nanos() % step.as_nanos()) as u64);
+        }
+
+        steps
+    }
+
+    pub fn advance(&mut self, delta_time: DeltaTime) {
+        self.elapsed += delta_time.delta();
+        self.ticks += 1;
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self {
+            last_time: Instant::now(),
+            accumulator: Duration::default(),
+            elapsed: Duration::default(),
+            ticks: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_accumulate() {
+        let mut clock: Clock = Clock::default();
+        let step: DeltaTime = DeltaTime::new(0.01);
+
+        assert_eq!(
+            clock.accumulate(Duration::from_millis(25), 1.0, step, 10),
+            2
+        );
+        assert_eq!(clock.accumulate(Duration::from_millis(5), 1.0, step, 10), 1);
+        assert_eq!(
+            clock.accumulate(Duration::from_millis(10), 0.5, step, 10),
+            0
+        );
+        assert_eq!(
+            clock.accumulate(Duration::from_millis(10), 2.0, step, 10),
+            2
+        );
+        assert_eq!(
+            clock.accumulate(Duration::from_millis(10), 0.0, step, 10),
+            0
+        );
+        assert_eq!(
+            clock.accumulate(Duration::from_secs(1), f32::MAX, step, 10),
+            10
+        );
+    }
+
+    #[test]
+    fn clock_accumulate_stall() {
+        let mut clock: Clock = Clock::default();
+        let step: DeltaTime = DeltaTime::new(0.01);
+
+        assert_eq!(clock.accumulate(Duration::from_secs(5), 1.0, step, 4), 4);
+        assert_eq!(clock.accumulate(Duration::ZERO, 1.0, step, 4), 0);
+        assert_eq!(
+            clock.accumulate(Duration::from_secs(1), 1.0, DeltaTime::new(0.0), 4),
+            0
+        );
+    }
+
+    #[test]
+    fn clock_advance() {
+        let mut clock: Clock = Clock::default();
+        let step: DeltaTime = DeltaTime::new(0.5);
+
+        clock.advance(step);
+        clock.advance(step);
+
+        assert_eq!(clock.elapsed(), Duration::from_secs(1));
+        assert_eq!(clock.ticks(), 2);
+    }
+}
@@ -14,9 +14,12 @@
  * limitations under the License.
  */
 
+use crate::{Arena, ConfigError, RemovalPolicy, ResizePolicy, SpawnPattern};
 use shipyard::Unique;
 
-#[derive(Unique, Debug, Copy, Clone, PartialEq)]
+const MIN_POLYGON_AREA: f32 = 1.0;
+
+#[derive(Unique, Debug, Clone, PartialEq)]
 pub struct Config {
     pub mouse_pos: Option<[f32; 2]>,
     pub mouse_vel: Option<[f32; 2]>,
@@ -24,11 +27,25 @@ pub struct Config {
 
     pub running: bool,
     pub paused: bool,
+    pub steps: usize,
+
+    pub time_step: f32,
+    pub max_sub_steps: usize,
+    pub time_scale: f32,
 
+    pub origin: [f32; 2],
     pub width: usize,
     pub height: usize,
+    pub arena: Arena,
+    pub chunk_size: Option<f32>,
 
     pub entity_count: usize,
+    pub spawn_pattern: SpawnPattern,
+    pub removal_policy: RemovalPolicy,
+    pub resize_policy: ResizePolicy,
+
+    pub predator_count: usize,
+    pub predator_speed: f32,
 
     pub direction_change_prob: f64,
     pub speed_change_prob: f64,
@@ -40,6 +57,168 @@ pub struct Config {
     pub attraction_radius: f32,
     pub alignment_radius: f32,
     pub avoidance_radius: f32,
+
+    pub threat_radius: f32,
+    pub escape_speed: f32,
+    pub burst_speed: f32,
+    pub refractory_period: f32,
+}
+
+impl Config {
+    pub const MAX_TIME_SCALE: f32 = 100.0;
+
+    pub fn interaction_radius(&self) -> f32 {
+        self.attraction_radius
+            .max(self.alignment_radius)
+            .max(self.avoidance_radius)
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.width == 0 || self.height == 0 {
+            return Err(ConfigError::EmptyArea {
+                width: self.width,
+                height: self.height,
+            });
+        }
+        if !self.origin.iter().all(|v| v.is_finite()) {
+            return Err(ConfigError::NonFinite { field: "origin" });
+        }
+        if !(self.time_step.is_finite() && self.time_step > 0.0) {
+            return Err(ConfigError::NonPositive {
+                field: "time_step",
+                value: self.time_step,
+            });
+        }
+        if self.max_sub_steps == 0 {
+            return Err(ConfigError::NoSubSteps);
+        }
+        if let Some(size) = self.chunk_size
+            && !(size.is_finite() && size > 0.0)
+        {
+            return Err(ConfigError::NonPositive {
+                field: "chunk_size",
+                value: size,
+            });
+        }
+        if let Arena::Polygon(vertices) = &self.arena {
+            if vertices.len() < 3 {
+                return Err(ConfigError::DegeneratePolygon {
+                    vertices: vertices.len(),
+                });
+            }
+            if !vertices.iter().all(|vertex| vertex.is_finite()) {
+                return Err(ConfigError::NonFinite { field: "arena" });
+            }
+            let area: f32 = Arena::signed_area(vertices).abs();
+            if area < MIN_POLYGON_AREA {
+                return Err(ConfigError::FlatPolygon { area });
+            }
+        }
+        self.spawn_pattern
+            .validate()
+            .map_err(ConfigError::SpawnPattern)?;
+
+        for (field, value) in [
+            ("direction_change_prob", self.direction_change_prob),
+            ("speed_change_prob", self.speed_change_prob),
+            ("stress_change_prob", self.stress_change_prob),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(ConfigError::Probability { field, value });
+            }
+        }
+
+        for (field, value) in [
+            ("attraction_fov", self.attraction_fov),
+            ("alignment_fov", self.alignment_fov),
+        ] {
+            if !(0.0..=360.0).contains(&value) {
+                return Err(ConfigError::FieldOfView { field, value });
+            }
+        }
+
+        for (field, value) in [
+            ("time_scale", self.time_scale),
+            ("avoidance_radius", self.avoidance_radius),
+            ("predator_speed", self.predator_speed),
+            ("threat_radius", self.threat_radius),
+            ("escape_speed", self.escape_speed),
+            ("burst_speed", self.burst_speed),
+            ("refractory_period", self.refractory_period),
+        ] {
+            if !(value.is_finite() && value >= 0.0) {
+                return Err(ConfigError::Negative { field, value });
+            }
+        }
+
+        if self.time_scale > Self::MAX_TIME_SCALE {
+            return Err(ConfigError::TooLarge {
+                field: "time_scale",
+                value: self.time_scale,
+                max: Self::MAX_TIME_SCALE,
+            });
+        }
+
+        if !(self.avoidance_radius < self.alignment_radius
+            && self.alignment_radius < self.attraction_radius
+            && self.attraction_radius.is_finite())
+        {
+            return Err(ConfigError::RadiusOrder {
+                avoidance: self.avoidance_radius,
+                alignment: self.alignment_radius,
+                attraction: self.attraction_radius,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn apply_changes(&mut self, from: &Self, to: &Self) {
+        macro_rules! apply_changed_fields {
+            ($($field:ident),* $(,)?) => {
+                $(
+                    if from.$field != to.$field {
+                        self.$field = to.$field.clone();
+                    }
+                )*
+            };
+        }
+
+        apply_changed_fields!(
+            mouse_pos,
+            mouse_vel,
+            mouse_speed,
+            running,
+            paused,
+            steps,
+            time_step,
+            max_sub_steps,
+            time_scale,
+            origin,
+            width,
+            height,
+            arena,
+            chunk_size,
+            entity_count,
+            spawn_pattern,
+            removal_policy,
+            resize_policy,
+            predator_count,
+            predator_speed,
+            direction_change_prob,
+            speed_change_prob,
+            stress_change_prob,
+            attraction_fov,
+            alignment_fov,
+            attraction_radius,
+            alignment_radius,
+            avoidance_radius,
+            threat_radius,
+            escape_speed,
+            burst_speed,
+            refractory_period,
+        );
+    }
 }
 
 impl Default for Config {
@@ -51,11 +230,25 @@ impl Default for Config {
 
             running: true,
             paused: false,
+            steps: 0,
 
+            time_step: 1.0 / 60.0,
+            max_sub_steps: 8,
+            time_scale: 1.0,
+
+            origin: [0.0, 0.0],
             width: 1_920,
             height: 1_080,
+            arena: Arena::Rectangle,
+            chunk_size: None,
 
             entity_count: 500,
+            spawn_pattern: SpawnPattern::Uniform,
+            removal_policy: RemovalPolicy::Random,
+            resize_policy: ResizePolicy::Scale,
+
+            predator_count: 0,
+            predator_speed: 80.0,
 
             direction_change_prob: 0.1,
             speed_change_prob: 0.05,
@@ -67,6 +260,120 @@ impl Default for Config {
             attraction_radius: 50.0,
             alignment_radius: 30.0,
             avoidance_radius: 10.0,
+
+            threat_radius: 80.0,
+            escape_speed: 100.0,
+            burst_speed: 250.0,
+            refractory_period: 2.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vec2;
+
+    #[test]
+    fn config_validate() {
+        assert_eq!(Config::default().validate(), Ok(()));
+
+        let invalid: [(Config, ConfigError); 9] = [
+            (
+                Config {
+                    width: 0,
+                    ..Config::default()
+                },
+                ConfigError::EmptyArea {
+                    width: 0,
+                    height: 1_080,
+                },
+            ),
+            (
+                Config {
+                    time_step: 0.0,
+                    ..Config::default()
+                },
+                ConfigError::NonPositive {
+                    field: "time_step",
+                    value: 0.0,
+                },
+            ),
+            (
+                Config {
+                    time_scale: f32::MAX,
+                    ..Config::default()
+                },
+                ConfigError::TooLarge {
+                    field: "time_scale",
+                    value: f32::MAX,
+                    max: Config::MAX_TIME_SCALE,
+                },
+            ),
+            (
+                Config {
+                    speed_change_prob: 1.5,
+                    ..Config::default()
+                },
+                ConfigError::Probability {
+                    field: "speed_change_prob",
+                    value: 1.5,
+                },
+            ),
+            (
+                Config {
+                    threat_radius: -1.0,
+                    ..Config::default()
+                },
+                ConfigError::Negative {
+                    field: "threat_radius",
+                    value: -1.0,
+                },
+            ),
+            (
+                Config {
+                    avoidance_radius: 30.0,
+                    ..Config::default()
+                },
+                ConfigError::RadiusOrder {
+                    avoidance: 30.0,
+                    alignment: 30.0,
+                    attraction: 50.0,
+                },
+            ),
+            (
+                Config {
+                    arena: Arena::Polygon(vec![Vec2::ZERO, Vec2::new(1.0, 0.0)]),
+                    ..Config::default()
+                },
+                ConfigError::DegeneratePolygon { vertices: 2 },
+            ),
+            (
+                Config {
+                    arena: Arena::Polygon(vec![
+                        Vec2::ZERO,
+                        Vec2::new(f32::NAN, 0.0),
+                        Vec2::new(0.0, 1.0),
+                    ]),
+                    ..Config::default()
+                },
+                ConfigError::NonFinite { field: "arena" },
+            ),
+            (
+                Config {
+                    arena: Arena::Polygon(vec![
+                        Vec2::ZERO,
+                        Vec2::new(50.0, 50.0),
+                        Vec2::new(100.0, 100.0),
+                    ]),
+                    ..Config::default()
+                },
+                ConfigError::FlatPolygon { area: 0.0 },
+            ),
+        ];
+
+        for (cfg, error) in invalid {
+            assert_eq!(cfg.validate(), Err(error));
         }
     }
 }
@@ -15,32 +15,27 @@
  */
 
 use shipyard::Unique;
-use std::{
-    ops::Mul,
-    time::{Duration, Instant},
-};
+use std::{ops::Mul, time::Duration};
 
-#[derive(Unique, Debug, Copy, Clone)]
+#[derive(Unique, Debug, Default, Copy, Clone)]
 pub struct DeltaTime {
-    last_time: Instant,
     delta: Duration,
 }
 
 impl DeltaTime {
-    pub fn calc(&mut self) {
-        let now: Instant = Instant::now();
-        self.delta = now - self.last_time;
-        self.last_time = now;
-    }
-}
-
-impl Default for DeltaTime {
-    fn default() -> Self {
+    pub fn new(time_step: f32) -> Self {
         Self {
-            last_time: Instant::now(),
-            delta: Duration::default(),
+            delta: Duration::try_from_secs_f32(time_step).unwrap_or_default(),
         }
     }
+
+    pub fn set(&mut self, time_step: f32) {
+        *self = Self::new(time_step);
+    }
+
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
 }
 
 impl Mul<f32> for DeltaTime {
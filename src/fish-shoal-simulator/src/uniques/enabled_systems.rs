@@ -0,0 +1,56 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::SimulationSystem;
+use shipyard::Unique;
+use std::collections::HashSet;
+
+#[derive(Unique, Debug, Default, Clone, PartialEq)]
+pub struct EnabledSystems {
+    disabled: HashSet<SimulationSystem>,
+}
+
+impl EnabledSystems {
+    pub fn is_enabled(&self, system: SimulationSystem) -> bool {
+        !self.disabled.contains(&system)
+    }
+
+    pub fn set_enabled(&mut self, system: SimulationSystem, enabled: bool) {
+        if enabled {
+            self.disabled.remove(&system);
+        } else {
+            self.disabled.insert(system);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enabled_systems_set_enabled() {
+        let mut systems: EnabledSystems = EnabledSystems::default();
+        assert!(SimulationSystem::ALL.iter().all(|&s| systems.is_enabled(s)));
+
+        systems.set_enabled(SimulationSystem::Hunt, false);
+        assert!(!systems.is_enabled(SimulationSystem::Hunt));
+        assert!(systems.is_enabled(SimulationSystem::Swarming));
+
+        systems.set_enabled(SimulationSystem::Hunt, true);
+        assert!(systems.is_enabled(SimulationSystem::Hunt));
+    }
+}
@@ -0,0 +1,120 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{EventKind, SimulationEvent};
+use shipyard::Unique;
+use std::collections::{HashSet, VecDeque};
+
+/// The number of undrained events kept, the oldest being dropped first.
+const MAX_QUEUED_EVENTS: usize = 65_536;
+
+#[derive(Unique, Debug, Default)]
+pub struct Events {
+    enabled: HashSet<EventKind>,
+    queue: VecDeque<SimulationEvent>,
+}
+
+impl Events {
+    pub fn is_enabled(&self, kind: EventKind) -> bool {
+        self.enabled.contains(&kind)
+    }
+
+    pub fn set_enabled(&mut self, kind: EventKind, enabled: bool) {
+        if enabled {
+            self.enabled.insert(kind);
+        } else {
+            self.enabled.remove(&kind);
+            self.queue
+                .retain(|event: &SimulationEvent| event.kind() != kind);
+        }
+    }
+
+    pub fn push(&mut self, event: SimulationEvent) {
+        if self.is_enabled(event.kind()) {
+            if self.queue.len() == MAX_QUEUED_EVENTS {
+                self.queue.pop_front();
+            }
+            self.queue.push_back(event);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    pub fn drain(&mut self) -> Vec<SimulationEvent> {
+        self.queue.drain(..).collect()
+    }
+
+    pub fn drain_kind(&mut self, kind: EventKind) -> Vec<SimulationEvent> {
+        let (drained, kept): (VecDeque<SimulationEvent>, VecDeque<SimulationEvent>) = self
+            .queue
+            .drain(..)
+            .partition(|event: &SimulationEvent| event.kind() == kind);
+        self.queue = kept;
+        drained.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_filter_and_drain() {
+        let mut events: Events = Events::default();
+        events.push(SimulationEvent::JoinedGroup { tick: 1, fish: 0 });
+        assert!(events.is_empty());
+
+        events.set_enabled(EventKind::JoinedGroup, true);
+        events.set_enabled(EventKind::LeftGroup, true);
+        events.push(SimulationEvent::JoinedGroup { tick: 1, fish: 0 });
+        events.push(SimulationEvent::LeftGroup { tick: 2, fish: 0 });
+        events.push(SimulationEvent::JoinedGroup { tick: 3, fish: 1 });
+
+        assert_eq!(
+            events.drain_kind(EventKind::JoinedGroup),
+            vec![
+                SimulationEvent::JoinedGroup { tick: 1, fish: 0 },
+                SimulationEvent::JoinedGroup { tick: 3, fish: 1 },
+            ]
+        );
+        assert_eq!(events.len(), 1);
+
+        events.set_enabled(EventKind::LeftGroup, false);
+        assert!(events.drain().is_empty());
+    }
+
+    #[test]
+    fn events_capacity() {
+        let mut events: Events = Events::default();
+        events.set_enabled(EventKind::JoinedGroup, true);
+        for tick in 0..MAX_QUEUED_EVENTS as u64 + 10 {
+            events.push(SimulationEvent::JoinedGroup { tick, fish: 0 });
+        }
+
+        let drained: Vec<SimulationEvent> = events.drain();
+        assert_eq!(drained.len(), MAX_QUEUED_EVENTS);
+        assert_eq!(
+            drained[0],
+            SimulationEvent::JoinedGroup { tick: 10, fish: 0 }
+        );
+    }
+}
@@ -0,0 +1,45 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use shipyard::Unique;
+use std::ops::Range;
+
+#[derive(Unique, Debug, Default)]
+pub struct IdGenerator {
+    next: usize,
+}
+
+impl IdGenerator {
+    pub fn reserve(&mut self, amount: usize) -> Range<usize> {
+        let start: usize = self.next;
+        self.next += amount;
+        start..self.next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IdGenerator;
+
+    #[test]
+    fn id_generator_reserve() {
+        let mut generator: IdGenerator = IdGenerator::default();
+
+        assert_eq!(generator.reserve(3), 0..3);
+        assert_eq!(generator.reserve(0), 3..3);
+        assert_eq!(generator.reserve(2), 3..5);
+    }
+}
@@ -15,9 +15,21 @@
  */
 
 mod chunks;
+mod clock;
 mod config;
 mod delta_time;
+mod enabled_systems;
+mod events;
+mod id_generator;
+mod random_source;
+mod threats;
 
 pub use chunks::Chunks;
+pub use clock::Clock;
 pub use config::Config;
 pub use delta_time::DeltaTime;
+pub use enabled_systems::EnabledSystems;
+pub use events::Events;
+pub use id_generator::IdGenerator;
+pub use random_source::RandomSource;
+pub use threats::{Threat, Threats};
@@ -0,0 +1,59 @@
+/*
+ * Copyright 2025 Nicolas Spijkerman
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use shipyard::Unique;
+
+#[derive(Unique, Debug, Clone)]
+pub struct RandomSource {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl RandomSource {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn fork(&mut self) -> StdRng {
+        StdRng::seed_from_u64(self.rng.random())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_source_fork() {
+        let mut a: RandomSource = RandomSource::new(7);
+        let mut b: RandomSource = RandomSource::new(7);
+
+        assert_eq!(a.seed(), 7);
+        assert_eq!(a.fork().random::<u64>(), b.fork().random::<u64>());
+        assert_ne!(
+            a.fork().random::<u64>(),
+            RandomSource::new(8).fork().random::<u64>()
+        );
+    }
+}